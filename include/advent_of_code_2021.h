@@ -0,0 +1,69 @@
+#ifndef ADVENT_OF_CODE_2021_H
+#define ADVENT_OF_CODE_2021_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * What `aoc_solve` managed to do.
+ */
+typedef enum AocStatus {
+  /**
+   * The answer was written out.
+   */
+  AOC_STATUS_OK = 0,
+  /**
+   * A required pointer was null.
+   */
+  AOC_STATUS_NULL_POINTER = 1,
+  /**
+   * There is no solution for the day.
+   */
+  AOC_STATUS_UNKNOWN_DAY = 2,
+  /**
+   * The part is not 1 or 2.
+   */
+  AOC_STATUS_UNKNOWN_PART = 3,
+  /**
+   * The input is not UTF-8, or the day could not parse it.
+   */
+  AOC_STATUS_PARSE_FAILED = 4,
+  /**
+   * The input parsed but the part could not be solved.
+   */
+  AOC_STATUS_SOLVE_FAILED = 5,
+  /**
+   * The solver panicked.
+   */
+  AOC_STATUS_PANICKED = 6,
+} AocStatus;
+
+/**
+ * Solves `part` of `day` against the `input_len` bytes at `input`, using the
+ * default parameters. On success `*answer` is set to the answer, otherwise to
+ * a message describing the failure. Either way, unless the status is
+ * `NullPointer`, the string must be released with `aoc_free_string`.
+ *
+ * # Safety
+ *
+ * `input` must point to `input_len` readable bytes, or may be null when
+ * `input_len` is 0. `answer` must point to writable memory for a pointer.
+ */
+enum AocStatus aoc_solve(uint8_t day,
+                         uint8_t part,
+                         const uint8_t *input,
+                         size_t input_len,
+                         char **answer);
+
+/**
+ * Releases a string returned by `aoc_solve`. Does nothing when given null.
+ *
+ * # Safety
+ *
+ * `string` must come from `aoc_solve` and not have been freed already.
+ */
+void aoc_free_string(char *string);
+
+#endif  /* ADVENT_OF_CODE_2021_H */
@@ -0,0 +1,222 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::solution::{Day, Params};
+
+/// Summary of repeated timings of one step, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub std_dev_ns: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut samples: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        samples.sort_unstable();
+        let len = samples.len();
+        if len == 0 {
+            return Stats {
+                min_ns: 0,
+                median_ns: 0,
+                p95_ns: 0,
+                std_dev_ns: 0.0,
+            };
+        }
+
+        let median_ns = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+        // nearest rank, so a handful of samples still gives an observed value
+        let p95_ns = samples[((len as f64 * 0.95).ceil() as usize).max(1) - 1];
+
+        let mean = samples.iter().sum::<u64>() as f64 / len as f64;
+        let variance = samples
+            .iter()
+            .map(|&s| (s as f64 - mean).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        Stats {
+            min_ns: samples[0],
+            median_ns,
+            p95_ns,
+            std_dev_ns: variance.sqrt(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DayBench {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayBench {
+    pub fn steps(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+/// Parses and solves `day` `warmup` times without measuring, then
+/// `iterations` times timing every step.
+pub fn bench_day(
+    day: &Day,
+    input: &str,
+    params: &Params,
+    warmup: usize,
+    iterations: usize,
+) -> Result<DayBench> {
+    for _ in 0..warmup {
+        let parsed = day.solver.parse(input, params)?;
+        day.solver.part1(&parsed, params)?;
+        day.solver.part2(&parsed, params)?;
+    }
+
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = day.solver.parse(input, params)?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        day.solver.part1(&parsed, params)?;
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        day.solver.part2(&parsed, params)?;
+        part2.push(start.elapsed());
+    }
+
+    Ok(DayBench {
+        parse: Stats::from_samples(&parse),
+        part1: Stats::from_samples(&part1),
+        part2: Stats::from_samples(&part2),
+    })
+}
+
+/// Benchmark results keyed by `dayNN`, saved so later runs can be compared.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Baseline(BTreeMap<String, DayBench>);
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Baseline> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Could not read baseline from `{}`", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Invalid baseline in `{}`", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("Could not save baseline to `{}`", path.display()))
+    }
+
+    pub fn get(&self, day: u8) -> Option<&DayBench> {
+        self.0.get(&format!("day{:02}", day))
+    }
+
+    pub fn insert(&mut self, day: u8, bench: DayBench) {
+        self.0.insert(format!("day{:02}", day), bench);
+    }
+}
+
+/// How much slower, in percent, the median of `current` is than `baseline`.
+pub fn slowdown(current: &Stats, baseline: &Stats) -> f64 {
+    if baseline.median_ns == 0 {
+        return 0.0;
+    }
+    (current.median_ns as f64 / baseline.median_ns as f64 - 1.0) * 100.0
+}
+
+/// Prints the stats for every step, comparing them with `baseline` when given.
+/// Returns the number of steps that got slower than `threshold` percent.
+pub fn print_benches(
+    benches: &[(u8, DayBench)],
+    baseline: Option<&Baseline>,
+    threshold: f64,
+) -> usize {
+    let mut regressions = 0;
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>12}",
+        "Day", "Step", "Min", "Median", "P95", "Std dev", "vs baseline"
+    );
+    for (day, bench) in benches {
+        let previous = baseline.and_then(|b| b.get(*day));
+        for (i, (step, stats)) in bench.steps().into_iter().enumerate() {
+            let comparison = match previous {
+                Some(previous) => {
+                    let change = slowdown(stats, previous.steps()[i].1);
+                    if change > threshold {
+                        regressions += 1;
+                        format!("{:+.1}% SLOWER", change)
+                    } else {
+                        format!("{:+.1}%", change)
+                    }
+                }
+                None => String::new(),
+            };
+            let line = format!(
+                "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>12}",
+                day,
+                step,
+                format!("{:.2?}", Duration::from_nanos(stats.min_ns)),
+                format!("{:.2?}", Duration::from_nanos(stats.median_ns)),
+                format!("{:.2?}", Duration::from_nanos(stats.p95_ns)),
+                format!("{:.2?}", Duration::from_nanos(stats.std_dev_ns as u64)),
+                comparison
+            );
+            println!("{}", line.trim_end());
+        }
+    }
+    regressions
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use std::time::Duration;
+
+    use super::{slowdown, Stats};
+
+    fn stats(samples: &[u64]) -> Stats {
+        let samples: Vec<Duration> = samples.iter().map(|&n| Duration::from_nanos(n)).collect();
+        Stats::from_samples(&samples)
+    }
+
+    #[test]
+    fn test_from_samples() {
+        let s = stats(&[50, 10, 40, 20, 30]);
+        assert_eq!(s.min_ns, 10);
+        assert_eq!(s.median_ns, 30);
+        assert_eq!(s.p95_ns, 50);
+        assert!((s.std_dev_ns - 200f64.sqrt()).abs() < 1e-9);
+
+        let s = stats(&(1..=100).collect::<Vec<u64>>());
+        assert_eq!(s.median_ns, 50);
+        assert_eq!(s.p95_ns, 95);
+    }
+
+    #[test]
+    fn test_slowdown() {
+        assert_eq!(slowdown(&stats(&[150]), &stats(&[100])), 50.0);
+        assert_eq!(slowdown(&stats(&[50]), &stats(&[100])), -50.0);
+    }
+}
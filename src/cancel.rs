@@ -0,0 +1,138 @@
+use std::{
+    cell::RefCell,
+    error::Error,
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use anyhow::Result;
+
+/// Tells the solver running with it to give up. Clones share the same flag.
+#[derive(Debug, Clone, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn new() -> Token {
+        Token::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `token` as the current thread's token, which [`check`] looks at.
+pub fn with_token<T>(token: Token, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.with(|current| current.replace(Some(token)));
+    let result = f();
+    CURRENT.with(|current| current.replace(previous));
+    result
+}
+
+/// Fails once the current thread's token is cancelled. Solvers that can loop
+/// for a long time call this on every iteration.
+pub fn check() -> Result<()> {
+    let cancelled =
+        CURRENT.with(|current| current.borrow().as_ref().is_some_and(Token::is_cancelled));
+    if cancelled {
+        anyhow::bail!("Cancelled");
+    }
+    Ok(())
+}
+
+/// The error [`with_timeout`] fails with when the time is up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut(pub Duration);
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "timed out after {}s", self.0.as_secs_f64())
+    }
+}
+
+impl Error for TimedOut {}
+
+/// Runs `f`, giving up on it once `timeout` has passed. `f` runs on its own
+/// thread with a token that gets cancelled on timeout; a solver that never
+/// calls [`check`] is left running in the background. Without a timeout `f`
+/// simply runs on the calling thread.
+pub fn with_timeout<T, F>(timeout: Option<Duration>, f: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return f();
+    };
+    let token = Token::new();
+    let (sender, receiver) = mpsc::channel();
+    let solver_token = token.clone();
+    thread::spawn(move || {
+        // the receiver is gone if this took too long, nobody is left to tell
+        let _ = sender.send(with_token(solver_token, f));
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            Err(TimedOut(timeout).into())
+        }
+        Err(RecvTimeoutError::Disconnected) => anyhow::bail!("The solver panicked"),
+    }
+}
+
+#[cfg(test)]
+mod cancel_tests {
+    use std::{
+        sync::mpsc,
+        time::{Duration, Instant},
+    };
+
+    use super::{check, with_timeout, TimedOut};
+
+    #[test]
+    fn test_with_timeout() {
+        assert_eq!(with_timeout(None, || Ok(1)).unwrap(), 1);
+        assert_eq!(
+            with_timeout(Some(Duration::from_secs(10)), || Ok(2)).unwrap(),
+            2
+        );
+
+        let (sender, receiver) = mpsc::channel();
+        let start = Instant::now();
+        let err = with_timeout(Some(Duration::from_millis(50)), move || {
+            let result: anyhow::Result<()> = loop {
+                if let Err(err) = check() {
+                    break Err(err);
+                }
+            };
+            sender.send(()).unwrap();
+            result
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<TimedOut>(),
+            Some(&TimedOut(Duration::from_millis(50)))
+        );
+        assert_eq!(err.to_string(), "timed out after 0.05s");
+        assert!(start.elapsed() < Duration::from_secs(5));
+        // the loop notices the cancelled token and stops
+        receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        assert!(check().is_ok());
+    }
+}
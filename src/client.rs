@@ -0,0 +1,339 @@
+use std::{
+    env, fmt, fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use ureq::Agent;
+
+use crate::input::Inputs;
+
+/// Environment variable holding the session cookie used to talk to the site.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding the site to talk to, e.g. a local stub server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u16 = 2021;
+
+const USER_AGENT: &str = concat!(
+    "github.com/abhayk/advent-of-code-2021 ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+/// Minimum time between two requests made by the same client.
+const REQUEST_INTERVAL: Duration = Duration::from_secs(3);
+
+/// A client for an Advent of Code style site which spaces out its requests.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    last_request: Option<Instant>,
+}
+
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Wrong,
+    /// Nothing was checked as the last answer was too recent.
+    RateLimited {
+        wait_secs: u64,
+    },
+    /// The part was already solved, so nothing was checked.
+    AlreadySolved,
+    /// The response couldn't be read, so the answer may or may not have been
+    /// checked.
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site answers a submission with.
+    pub fn parse(page: &str) -> Option<Verdict> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else if page.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+            let wait_secs = wait.captures(page).map_or(0, |c| {
+                let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+                minutes * 60 + c[2].parse::<u64>().unwrap_or(0)
+            });
+            Some(Verdict::RateLimited { wait_secs })
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited { wait_secs } => write!(f, "rate limited, wait {}s", wait_secs),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .http_status_as_error(false)
+            .build()
+            .into();
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            last_request: None,
+        }
+    }
+
+    /// Builds a client from `$AOC_BASE_URL` and `$AOC_SESSION`, unless a base
+    /// URL is given explicitly.
+    pub fn from_env(base_url: Option<&str>) -> Result<Client> {
+        let session = env::var(SESSION_VAR)
+            .with_context(|| format!("Set `{}` to your session cookie", SESSION_VAR))?;
+        let base_url = match base_url {
+            Some(url) => url.to_string(),
+            None => env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+        };
+        Ok(Client::new(&base_url, &session))
+    }
+
+    /// Downloads the puzzle input for `day`.
+    pub fn download_input(&mut self, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        self.throttle();
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call()
+            .with_context(|| format!("Request to `{}` failed", url))?;
+        let status = response.status();
+        let body = response.body_mut().read_to_string()?;
+        if !status.is_success() {
+            anyhow::bail!(
+                "Could not download input for day {} from `{}` - {} {}",
+                day,
+                url,
+                status,
+                body.trim()
+            );
+        }
+        Ok(body)
+    }
+
+    /// Submits `answer` to `part` of `day`.
+    pub fn submit_answer(&mut self, day: u8, part: u8, answer: &str) -> Result<Verdict> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, day);
+        self.throttle();
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])
+            .with_context(|| format!("Request to `{}` failed", url))?;
+        let status = response.status();
+        let body = response.body_mut().read_to_string()?;
+        if !status.is_success() {
+            anyhow::bail!(
+                "Could not submit an answer for day {} to `{}` - {} {}",
+                day,
+                url,
+                status,
+                body.trim()
+            );
+        }
+        Verdict::parse(&body)
+            .with_context(|| format!("Could not make sense of the response from `{}`", url))
+    }
+
+    /// Saves the input for `day` into `inputs`, unless it is already there.
+    pub fn fetch(&mut self, day: u8, inputs: &Inputs) -> Result<Fetched> {
+        if !(1..=25).contains(&day) {
+            anyhow::bail!("There is no puzzle for day {}", day);
+        }
+        let path = inputs.path(day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+        let input = self.download_input(day)?;
+
+        // write to a temporary file first so a failed write never looks cached
+        fs::create_dir_all(inputs.dir())?;
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, input)
+            .and_then(|_| fs::rename(&tmp, &path))
+            .with_context(|| format!("Could not save input to `{}`", path.display()))?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    fn throttle(&mut self) {
+        if let Some(last) = self.last_request {
+            if let Some(wait) = REQUEST_INTERVAL.checked_sub(last.elapsed()) {
+                thread::sleep(wait);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod client_tests {
+    use std::{
+        env,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::{Client, Fetched, Verdict};
+    use crate::input::Inputs;
+
+    /// Serves `body` to a single request and returns the request line and
+    /// headers, followed by the request body if there was one.
+    fn stub_server(body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut request: Vec<String> = (&mut reader)
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect();
+            let length = request.iter().find_map(|h| {
+                let (name, value) = h.split_once(':')?;
+                name.eq_ignore_ascii_case("content-length")
+                    .then(|| value.trim().parse::<usize>().unwrap())
+            });
+            if let Some(length) = length {
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push(String::from_utf8(content).unwrap());
+            }
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (url, server) = stub_server("3,4,3,1,2\n");
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let inputs = Inputs::new(&dir);
+        let mut client = Client::new(&url, "secret");
+
+        assert!(matches!(
+            client.fetch(6, &inputs).unwrap(),
+            Fetched::Downloaded(_)
+        ));
+        assert_eq!(inputs.load(6).unwrap(), "3,4,3,1,2\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2021/day/6/input HTTP/1.1");
+        assert!(request
+            .iter()
+            .any(|h| h.eq_ignore_ascii_case("cookie: session=secret")));
+        assert!(request.iter().any(|h| h
+            .to_lowercase()
+            .starts_with("user-agent: github.com/abhayk")));
+
+        // the stub only answers once, so this has to come from the cache
+        assert!(matches!(
+            client.fetch(6, &inputs).unwrap(),
+            Fetched::Cached(_)
+        ));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (url, server) = stub_server(
+            "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        );
+        let mut client = Client::new(&url, "secret");
+        assert_eq!(
+            client.submit_answer(6, 2, "26984457539").unwrap(),
+            Verdict::TooHigh
+        );
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "POST /2021/day/6/answer HTTP/1.1");
+        assert_eq!(request.last().unwrap(), "level=2&answer=26984457539");
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let parse = Verdict::parse;
+        assert_eq!(
+            parse("That's the right answer!  You are one gold star"),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            parse("That's not the right answer.  If you're stuck"),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            parse(
+                "You gave an answer too recently; you have to wait.  You have 1m 5s left to wait."
+            ),
+            Some(Verdict::RateLimited { wait_secs: 65 })
+        );
+        assert_eq!(
+            parse("You gave an answer too recently.  You have 34s left to wait."),
+            Some(Verdict::RateLimited { wait_secs: 34 })
+        );
+        assert_eq!(
+            parse("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some(Verdict::AlreadySolved)
+        );
+        assert_eq!(parse("<html>Puzzle inputs differ by user.</html>"), None);
+    }
+}
@@ -0,0 +1,75 @@
+//! Day 1: Sonar Sweep.
+
+use anyhow::Result;
+
+use crate::solution::{Param, Params, Solution};
+
+/// The [`Solution`] for day 1.
+pub struct Day01;
+
+static PARAMS: &[Param] = &[Param::per_part(
+    "window",
+    "1",
+    "3",
+    "Distance between the measurements compared",
+)];
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Vec<u32>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Vec<u32>, params: &Params) -> Result<u32> {
+        Ok(count_increases_in_window(input, params.get("window")?))
+    }
+
+    fn part2(&self, input: &Vec<u32>, params: &Params) -> Result<u32> {
+        Ok(count_increases_in_window(input, params.get("window")?))
+    }
+}
+
+/// The depth measurements, one per line.
+pub fn parse_input(input: &str) -> Result<Vec<u32>> {
+    Ok(input
+        .lines()
+        .map(|s| s.parse::<u32>())
+        .collect::<Result<Vec<u32>, _>>()?)
+}
+
+/// How many times the sum of `window_size` consecutive measurements increases.
+pub fn count_increases_in_window(input: &[u32], window_size: usize) -> u32 {
+    let mut count = 0;
+    for i in window_size..input.len() {
+        if input[i] > input[i - window_size] {
+            count += 1
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod day01_tests {
+    use super::count_increases_in_window;
+
+    fn get_test_input() -> Vec<u32> {
+        vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263]
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(count_increases_in_window(&get_test_input(), 1), 7);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(count_increases_in_window(&get_test_input(), 3), 5);
+    }
+}
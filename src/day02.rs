@@ -0,0 +1,105 @@
+//! Day 2: Dive!.
+
+use anyhow::{Context, Result};
+
+use crate::solution::{Params, Solution};
+
+/// The [`Solution`] for day 2.
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(i32, i32)>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Vec<(i32, i32)>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                parse_input(line).with_context(|| format!("Invalid command on line {}", i + 1))
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Vec<(i32, i32)>, _params: &Params) -> Result<i32> {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input: &Vec<(i32, i32)>, _params: &Params) -> Result<i32> {
+        Ok(part2(input))
+    }
+}
+
+/// Horizontal position times depth, with `down` and `up` changing the depth.
+pub fn part1(moves: &[(i32, i32)]) -> i32 {
+    let (x, y) = moves
+        .iter()
+        .fold((0, 0), |(x, y), (dx, dy)| (x + dx, y + dy));
+    x * y
+}
+
+/// Horizontal position times depth, with `down` and `up` changing the aim.
+pub fn part2(moves: &[(i32, i32)]) -> i32 {
+    let (x, y, _) = moves.iter().fold((0, 0, 0), |(x, y, aim), (dx, dy)| {
+        (x + dx, y + aim * dx, aim + dy)
+    });
+    x * y
+}
+
+/// One command as `(forward, down)` deltas.
+pub fn parse_input(input: &str) -> Result<(i32, i32)> {
+    let mut tokens = input.split_whitespace();
+    let (Some(direction), Some(value), None) = (tokens.next(), tokens.next(), tokens.next()) else {
+        anyhow::bail!("Expected a direction and a distance in `{}`", input);
+    };
+    let value: i32 = value.parse()?;
+    match direction {
+        "forward" => Ok((value, 0)),
+        "down" => Ok((0, value)),
+        "up" => Ok((0, -value)),
+        _ => anyhow::bail!("Unknown direction `{}`", direction),
+    }
+}
+
+#[cfg(test)]
+mod day02_tests {
+    use super::{part1, part2, Day02};
+    use crate::solution::{Params, Solution};
+
+    static TEST_INPUT: &str = r"forward 5
+    down 5
+    forward 8
+    up 3
+    down 8
+    forward 2";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            part1(&Day02.parse(TEST_INPUT, &Params::default()).unwrap()),
+            150
+        )
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            part2(&Day02.parse(TEST_INPUT, &Params::default()).unwrap()),
+            900
+        )
+    }
+
+    #[test]
+    fn test_parse() {
+        let err = Day02
+            .parse("forward 5\nhello world", &Params::default())
+            .unwrap_err();
+        assert_eq!(
+            format!("{:#}", err),
+            "Invalid command on line 2: invalid digit found in string"
+        );
+        assert!(Day02.parse("sideways 1", &Params::default()).is_err());
+        assert!(Day02.parse("up", &Params::default()).is_err());
+    }
+}
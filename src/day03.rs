@@ -0,0 +1,157 @@
+//! Day 3: Binary Diagnostic.
+
+use std::cmp::Ordering;
+
+use anyhow::Result;
+
+use crate::solution::{Params, Solution};
+
+/// The [`Solution`] for day 3.
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Vec<String>> {
+        let lines: Vec<String> = input.lines().map(String::from).collect();
+        let Some(first) = lines.first() else {
+            anyhow::bail!("The report is empty");
+        };
+        for (i, line) in lines.iter().enumerate() {
+            if let Some(c) = line.chars().find(|c| !matches!(c, '0' | '1')) {
+                anyhow::bail!("Unexpected {:?} on line {}", c, i + 1);
+            }
+            if line.len() != first.len() {
+                anyhow::bail!(
+                    "Line {} has {} bits where the first has {}",
+                    i + 1,
+                    line.len(),
+                    first.len()
+                );
+            }
+        }
+        Ok(lines)
+    }
+
+    fn part1(&self, input: &Vec<String>, _params: &Params) -> Result<u32> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Vec<String>, _params: &Params) -> Result<u32> {
+        part2(input)
+    }
+}
+
+/// The power consumption: gamma rate times epsilon rate.
+pub fn part1(lines: &[String]) -> Result<u32> {
+    let mut counts = vec![0; lines[0].len()];
+    lines.iter().for_each(|line| {
+        line.chars()
+            .enumerate()
+            .filter(|(_i, c)| *c == '1')
+            .for_each(|(i, _c)| counts[i] += 1)
+    });
+    let len = lines.len();
+    let gamma_str: String = counts
+        .iter()
+        .map(|c| if c > &(len / 2) { '1' } else { '0' })
+        .collect();
+    let epsilon_str: String = counts
+        .iter()
+        .map(|c| if c > &(len / 2) { '0' } else { '1' })
+        .collect();
+    let gamma = u32::from_str_radix(&gamma_str, 2)?;
+    let epsilon = u32::from_str_radix(&epsilon_str, 2)?;
+    Ok(gamma * epsilon)
+}
+
+/// The life support rating: oxygen generator rating times CO2 scrubber rating.
+pub fn part2(lines: &[String]) -> Result<u32> {
+    let o2_rating_str = get_rating(lines, retain_marker_for_o2_rating)?;
+    let co2_rating_str = get_rating(lines, retain_marker_for_co2_rating)?;
+
+    let o2_rating = u32::from_str_radix(o2_rating_str, 2)?;
+    let co2_rating = u32::from_str_radix(co2_rating_str, 2)?;
+
+    Ok(o2_rating * co2_rating)
+}
+
+fn get_rating(input: &[String], get_retain_marker: fn(u32, u32) -> char) -> Result<&str> {
+    let mut lines: Vec<&str> = input.iter().map(String::as_str).collect();
+    for i in 0..lines[0].len() {
+        let count_of_ones = lines
+            .iter()
+            .filter_map(|line| line.chars().nth(i))
+            .fold(0, |acc, c| if c == '1' { acc + 1 } else { acc });
+        let retain_marker = get_retain_marker(count_of_ones, lines.len() as u32);
+        lines.retain(|line| line.chars().nth(i) == Some(retain_marker));
+        if lines.len() == 1 {
+            return Ok(lines[0]);
+        }
+    }
+    Err(anyhow::anyhow!("Could not get rating"))
+}
+
+fn retain_marker_for_o2_rating(count_of_ones: u32, lines_count: u32) -> char {
+    let count_of_zeros = lines_count - count_of_ones;
+    match count_of_ones.cmp(&count_of_zeros) {
+        Ordering::Less => '0',
+        Ordering::Greater | Ordering::Equal => '1',
+    }
+}
+
+fn retain_marker_for_co2_rating(count_of_ones: u32, lines_count: u32) -> char {
+    let count_of_zeros = lines_count - count_of_ones;
+    match count_of_ones.cmp(&count_of_zeros) {
+        Ordering::Less => '1',
+        Ordering::Greater | Ordering::Equal => '0',
+    }
+}
+
+#[cfg(test)]
+mod day03_tests {
+    use super::{part1, part2, Day03};
+    use crate::solution::{Params, Solution};
+
+    static TEST_INPUT: &str = r"00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            part1(&Day03.parse(TEST_INPUT, &Params::default()).unwrap()).unwrap(),
+            198
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            part2(&Day03.parse(TEST_INPUT, &Params::default()).unwrap()).unwrap(),
+            230
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let params = Params::default();
+        assert!(Day03.parse("", &params).is_err());
+        assert!(Day03.parse("0101\n01", &params).is_err());
+        assert_eq!(
+            Day03.parse("01\n2a", &params).unwrap_err().to_string(),
+            "Unexpected '2' on line 2"
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! Day 4: Giant Squid.
+
+use std::fmt::Debug;
+
+use anyhow::Result;
+
+use crate::solution::{Param, Params, Solution};
+
+/// The [`Solution`] for day 4.
+pub struct Day04;
+
+static PARAMS: &[Param] = &[Param::new(
+    "board_size",
+    "5",
+    "Number of rows in each board",
+)];
+
+impl Solution for Day04 {
+    type Input = Bingo;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Bingo> {
+        parse_input(input, params.get("board_size")?)
+    }
+
+    fn part1(&self, input: &Bingo, _params: &Params) -> Result<u32> {
+        get_winning_scores(input)
+            .first()
+            .copied()
+            .ok_or(anyhow::anyhow!("No board won"))
+    }
+
+    fn part2(&self, input: &Bingo, _params: &Params) -> Result<u32> {
+        get_winning_scores(input)
+            .last()
+            .copied()
+            .ok_or(anyhow::anyhow!("No board won"))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Cell {
+    value: u32,
+    marked: bool,
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Board {
+    cells: Vec<Cell>,
+    won: bool,
+}
+
+impl Cell {
+    fn mark(&mut self) {
+        self.marked = true;
+    }
+}
+
+impl Board {
+    fn init(input: &[&str]) -> Result<Board> {
+        let cells = input
+            .iter()
+            .enumerate()
+            .flat_map(|(i, line)| {
+                line.split_whitespace()
+                    .enumerate()
+                    .map(|(j, str)| {
+                        let value: u32 = str.parse()?;
+                        Ok(Cell {
+                            value,
+                            marked: false,
+                            x: i,
+                            y: j,
+                        })
+                    })
+                    .collect::<Vec<Result<Cell>>>()
+            })
+            .collect::<Result<Vec<Cell>>>()?;
+
+        Ok(Board { cells, won: false })
+    }
+
+    fn mark(&mut self, value: u32) -> Option<(usize, usize)> {
+        if let Some(cell) = self.cells.iter_mut().find(|cell| cell.value == value) {
+            cell.mark();
+            return Some((cell.x, cell.y));
+        }
+        None
+    }
+
+    fn is_complete(&self, row: usize, col: usize) -> bool {
+        self.is_line_complete(row, |cell| cell.x) || self.is_line_complete(col, |cell| cell.y)
+    }
+
+    fn is_line_complete<F>(&self, index: usize, get_cell_coordinate: F) -> bool
+    where
+        F: Fn(&Cell) -> usize,
+    {
+        !self
+            .cells
+            .iter()
+            .filter(|cell| get_cell_coordinate(cell) == index)
+            .any(|cell| !cell.marked)
+    }
+
+    fn calculate_score(&self, draw: u32) -> u32 {
+        let sum = self
+            .cells
+            .iter()
+            .filter(|cell| !cell.marked)
+            .map(|cell| cell.value)
+            .sum::<u32>();
+        sum * draw
+    }
+
+    fn set_won(&mut self) {
+        self.won = true;
+    }
+}
+
+/// The numbers to draw, and the boards before any of them are marked. Built
+/// with [`parse_input`] and played with [`get_winning_scores`].
+pub struct Bingo {
+    draws: Vec<u32>,
+    boards: Vec<Board>,
+}
+
+/// Reads the draws from the first line and the boards, of `board_size` rows
+/// each, from the rest. Fails if `board_size` is 0.
+pub fn parse_input(input: &str, board_size: usize) -> Result<Bingo> {
+    if board_size == 0 {
+        anyhow::bail!("Boards need at least one row");
+    }
+    let mut iter = input.lines();
+
+    let draws = iter
+        .next()
+        .ok_or(anyhow::anyhow!("Invalid input"))?
+        .split(',')
+        .map(|s| s.parse::<u32>())
+        .collect::<Result<Vec<u32>, _>>()?;
+
+    let boards = iter
+        .filter(|line| !line.trim().is_empty())
+        .collect::<Vec<&str>>()
+        .chunks(board_size)
+        .map(Board::init)
+        .collect::<Result<Vec<Board>>>()?;
+
+    Ok(Bingo { draws, boards })
+}
+
+/// Plays every draw, returning the score of each board in the order they win.
+/// Boards that never win are left out.
+pub fn get_winning_scores(bingo: &Bingo) -> Vec<u32> {
+    let mut boards = bingo.boards.clone();
+
+    bingo
+        .draws
+        .iter()
+        .flat_map(|draw| {
+            boards
+                .iter_mut()
+                .enumerate()
+                .filter(|(_, board)| !board.won)
+                .filter_map(|(index, board)| {
+                    if let Some((x, y)) = board.mark(*draw) {
+                        if board.is_complete(x, y) {
+                            board.set_won();
+                            let score = board.calculate_score(*draw);
+                            tracing::debug!(board = index, draw, score, "board won");
+                            return Some(score);
+                        }
+                    }
+                    None
+                })
+                .collect::<Vec<u32>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod day04_tests {
+    use super::{get_winning_scores, parse_input};
+
+    static TEST_INPUT: &str = r"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+    #[test]
+    fn test_both_parts() {
+        let scores = get_winning_scores(&parse_input(TEST_INPUT, 5).unwrap());
+        assert_eq!(*scores.first().unwrap(), 4512);
+        assert_eq!(*scores.last().unwrap(), 1924);
+        assert!(parse_input(TEST_INPUT, 0).is_err());
+    }
+}
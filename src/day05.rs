@@ -0,0 +1,133 @@
+//! Day 5: Hydrothermal Venture.
+
+use anyhow::Result;
+use regex::Regex;
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::{
+    cancel,
+    common::Point,
+    solution::{Param, Params, Solution},
+};
+
+/// The [`Solution`] for day 5.
+pub struct Day05;
+
+static PARAMS: &[Param] = &[Param::per_part(
+    "include_diagonals",
+    "false",
+    "true",
+    "Whether diagonal lines are counted",
+)];
+
+impl Solution for Day05 {
+    type Input = Vec<(Point, Point)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Vec<(Point, Point)>> {
+        let format = Regex::new(r"(\d*),(\d*) -> (\d*),(\d*)")?;
+        input
+            .lines()
+            .map(|line| parse_input(line, &format))
+            .collect()
+    }
+
+    fn part1(&self, input: &Vec<(Point, Point)>, params: &Params) -> Result<usize> {
+        calculate_overlap_count(input, params.get("include_diagonals")?)
+    }
+
+    fn part2(&self, input: &Vec<(Point, Point)>, params: &Params) -> Result<usize> {
+        calculate_overlap_count(input, params.get("include_diagonals")?)
+    }
+}
+
+/// Every point from `a` to `b`, both included. The line must be horizontal,
+/// vertical or at 45 degrees. Fails once the solver is cancelled, as a line
+/// can be arbitrarily long.
+pub fn generate_points_in_line(a: &Point, b: &Point) -> Result<Vec<Point>> {
+    let mut points: Vec<Point> = Vec::new();
+    let mut point = *a;
+    let step = Point::new(get_step(a.x, b.x) as i32, get_step(a.y, b.y) as i32);
+    while point != *b {
+        cancel::check()?;
+        points.push(point);
+        point += step;
+    }
+    points.push(*b);
+    Ok(points)
+}
+
+fn get_step(m: i32, target: i32) -> i8 {
+    match m.cmp(&target) {
+        Ordering::Less => 1,
+        Ordering::Greater => -1,
+        Ordering::Equal => 0,
+    }
+}
+
+/// Reads one line of vents, which must be horizontal, vertical or at 45
+/// degrees.
+fn parse_input(input: &str, format: &Regex) -> Result<(Point, Point)> {
+    let items = format
+        .captures(input)
+        .ok_or(anyhow::anyhow!("Error during captures"))?
+        .iter()
+        .skip(1)
+        .flatten()
+        .map(|item| item.as_str().parse())
+        .collect::<Result<Vec<i32>, _>>()?;
+    let (a, b) = (
+        Point::new(items[0], items[1]),
+        Point::new(items[2], items[3]),
+    );
+    if a.x != b.x && a.y != b.y && a.x.abs_diff(b.x) != a.y.abs_diff(b.y) {
+        anyhow::bail!("`{}` is not horizontal, vertical or at 45 degrees", input);
+    }
+    Ok((a, b))
+}
+
+/// How many points at least two of the lines go through. Diagonal lines are
+/// skipped unless `include_diagonals`. Fails once the solver is cancelled.
+pub fn calculate_overlap_count(input: &[(Point, Point)], include_diagonals: bool) -> Result<usize> {
+    let mut overlap_counts = HashMap::new();
+    for (a, b) in input
+        .iter()
+        .filter(|(a, b)| include_diagonals || (a.x == b.x || a.y == b.y))
+    {
+        for point in generate_points_in_line(a, b)? {
+            *overlap_counts.entry(point).or_insert(0) += 1;
+        }
+    }
+
+    Ok(overlap_counts.values().filter(|&&count| count > 1).count())
+}
+
+#[cfg(test)]
+mod day05_tests {
+    use super::{calculate_overlap_count, Day05};
+    use crate::solution::{Params, Solution};
+
+    #[test]
+    fn test_both_parts() {
+        let input = r"0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+        let lines = Day05.parse(input, &Params::default()).unwrap();
+        assert_eq!(calculate_overlap_count(&lines, false).unwrap(), 5);
+        assert_eq!(calculate_overlap_count(&lines, true).unwrap(), 12);
+        assert!(Day05.parse("0,0 -> 2,5", &Params::default()).is_err());
+    }
+}
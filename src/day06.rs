@@ -0,0 +1,85 @@
+//! Day 6: Lanternfish.
+
+use anyhow::Result;
+
+use crate::solution::{Param, Params, Solution};
+
+/// The [`Solution`] for day 6.
+pub struct Day06;
+
+static PARAMS: &[Param] = &[Param::per_part(
+    "days",
+    "80",
+    "256",
+    "Number of days simulated",
+)];
+
+impl Solution for Day06 {
+    type Input = Vec<usize>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Vec<usize>> {
+        let timers = input
+            .trim()
+            .split(',')
+            .map(|s| s.parse())
+            .collect::<Result<Vec<usize>, _>>()?;
+        if let Some(timer) = timers.iter().find(|&&timer| timer > 8) {
+            anyhow::bail!("Timer {} is above 8", timer);
+        }
+        Ok(timers)
+    }
+
+    fn part1(&self, input: &Vec<usize>, params: &Params) -> Result<u64> {
+        simulate(input, params.get("days")?)
+    }
+
+    fn part2(&self, input: &Vec<usize>, params: &Params) -> Result<u64> {
+        simulate(input, params.get("days")?)
+    }
+}
+
+/// How many lanternfish there are after `days`, starting from the given timers.
+/// Fails if the count no longer fits in a `u64`.
+pub fn simulate(input: &[usize], days: u32) -> Result<u64> {
+    let mut counts: Vec<u64> = vec![0; 9];
+
+    for &i in input {
+        counts[i] += 1;
+    }
+    for _ in 0..days {
+        let zeros = counts[0];
+        for age in 0..8 {
+            counts[age] = counts[age + 1];
+        }
+        counts[6] = counts[6]
+            .checked_add(zeros)
+            .ok_or(anyhow::anyhow!("Fish count overflows after {} days", days))?;
+        counts[8] = zeros;
+    }
+    counts
+        .iter()
+        .try_fold(0u64, |acc, c| acc.checked_add(*c))
+        .ok_or(anyhow::anyhow!("Fish count overflows after {} days", days))
+}
+
+#[cfg(test)]
+mod day06_tests {
+    use super::{simulate, Day06};
+    use crate::solution::{Params, Solution};
+
+    #[test]
+    fn test_simulate() {
+        let test_input = Day06.parse("3,4,3,1,2", &Params::default()).unwrap();
+        assert_eq!(simulate(&test_input, 18).unwrap(), 26);
+        assert_eq!(simulate(&test_input, 80).unwrap(), 5934);
+        assert_eq!(simulate(&test_input, 256).unwrap(), 26984457539);
+        assert!(simulate(&test_input, 1000).is_err());
+        assert!(Day06.parse("9,1", &Params::default()).is_err());
+    }
+}
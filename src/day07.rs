@@ -0,0 +1,126 @@
+//! Day 7: The Treachery of Whales.
+
+use std::num::ParseIntError;
+
+use anyhow::Result;
+
+use crate::{
+    cancel,
+    solution::{Params, Solution},
+};
+
+/// The [`Solution`] for day 7.
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Vec<i32>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Vec<i32>, _params: &Params) -> Result<i32> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Vec<i32>, _params: &Params) -> Result<i32> {
+        part2(input)
+    }
+}
+
+/// The comma separated crab positions.
+pub fn parse_input(input: &str) -> Result<Vec<i32>> {
+    input
+        .trim()
+        .split(',')
+        .map(|pos| {
+            pos.parse().map_err(|err: ParseIntError| {
+                anyhow::anyhow!("Error parsing input `{}` - {}", pos, err)
+            })
+        })
+        .collect::<Result<Vec<i32>, _>>()
+}
+
+/// The least fuel needed to align every crab, one unit of fuel per step.
+pub fn part1(positions: &[i32]) -> Result<i32> {
+    least_fuel(positions, positions.iter().copied(), |distance| distance)
+}
+
+/// The least fuel needed to align every crab, each step costing one more than
+/// the last.
+pub fn part2(positions: &[i32]) -> Result<i32> {
+    let mut positions = positions.to_vec();
+    positions.sort_unstable();
+    let (&first, &last) = positions
+        .first()
+        .zip(positions.last())
+        .ok_or(anyhow::anyhow!("There are no crabs"))?;
+
+    least_fuel(&positions, first..=last, |distance| {
+        (distance * (distance + 1)) / 2
+    })
+}
+
+/// The least fuel needed to move every crab to one of `targets`, `cost` being
+/// the fuel for moving a crab some distance. Trying each target goes over
+/// every crab, so this checks for cancellation before each one.
+fn least_fuel(
+    positions: &[i32],
+    targets: impl Iterator<Item = i32>,
+    cost: impl Fn(i32) -> i32,
+) -> Result<i32> {
+    let mut least = None;
+    for target in targets {
+        cancel::check()?;
+        let fuel = positions
+            .iter()
+            .map(|source| cost((target - source).abs()))
+            .sum::<i32>();
+        least = Some(least.map_or(fuel, |least: i32| least.min(fuel)));
+    }
+    least.ok_or(anyhow::anyhow!("There are no crabs"))
+}
+
+#[cfg(test)]
+mod day07_tests {
+    use std::{sync::mpsc, time::Duration};
+
+    use super::{parse_input, part1, part2};
+    use crate::cancel::{self, TimedOut};
+
+    static TEST_INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()).unwrap(), 37);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()).unwrap(), 168);
+    }
+
+    #[test]
+    fn test_one_position() {
+        assert_eq!(part1(&parse_input("5").unwrap()).unwrap(), 0);
+        assert_eq!(part2(&parse_input("5,5").unwrap()).unwrap(), 0);
+        assert!(part1(&[]).is_err());
+        assert!(part2(&[]).is_err());
+    }
+
+    #[test]
+    fn test_timed_out_stops() {
+        // several seconds of work if it ran to the end
+        let positions: Vec<i32> = (0..40_000).collect();
+        let (sender, receiver) = mpsc::channel();
+        let err = cancel::with_timeout(Some(Duration::from_millis(50)), move || {
+            sender.send(part1(&positions).is_err()).unwrap();
+            Ok(())
+        })
+        .unwrap_err();
+        assert!(err.is::<TimedOut>());
+        // part 1 notices the cancelled token and gives up instead of finishing
+        assert!(receiver.recv_timeout(Duration::from_secs(5)).unwrap());
+    }
+}
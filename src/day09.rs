@@ -0,0 +1,106 @@
+//! Day 9: Smoke Basin.
+
+use std::collections::{HashSet, VecDeque};
+
+use anyhow::Result;
+
+use crate::{
+    common::{Direction, Grid, Point},
+    solution::{Params, Solution},
+};
+
+/// The [`Solution`] for day 9.
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Grid;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Grid> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Grid, _params: &Params) -> Result<u32> {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input: &Grid, _params: &Params) -> Result<u32> {
+        Ok(part2(input))
+    }
+}
+
+/// The sum of the risk levels of the low points.
+pub fn part1(grid: &Grid) -> u32 {
+    low_point_iter(grid).map(|(_, val)| val + 1).sum()
+}
+
+/// The product of the sizes of the three largest basins.
+pub fn part2(grid: &Grid) -> u32 {
+    let mut basin_sizes = low_point_iter(grid)
+        .map(|(point, _)| {
+            let size = basin_size(grid, point);
+            tracing::debug!(x = point.x, y = point.y, size, "found basin");
+            size
+        })
+        .collect::<Vec<u32>>();
+
+    basin_sizes.sort_unstable();
+    basin_sizes.iter().rev().take(3).product()
+}
+
+/// The number of locations in the basin flowing down to `low_point`.
+pub fn basin_size(grid: &Grid, low_point: Point) -> u32 {
+    let mut visited = HashSet::new();
+    visited.insert(low_point);
+
+    let mut stack = VecDeque::new();
+    stack.push_back(low_point);
+
+    while !stack.is_empty() {
+        let point = stack.pop_front().unwrap();
+        for next in grid
+            .neighbors(point, &Direction::CARDINAL)
+            .filter(|&next| grid[next] != 9)
+        {
+            if grid[next] > grid[point] {
+                visited.insert(next);
+                stack.push_back(next);
+            }
+        }
+    }
+    visited.len() as u32
+}
+
+fn low_point_iter(grid: &Grid) -> impl Iterator<Item = (Point, &u32)> {
+    grid.iter_points().filter(|&(point, &val)| {
+        grid.neighbors(point, &Direction::CARDINAL)
+            .all(|next| val < grid[next])
+    })
+}
+
+/// Reads the height map.
+pub fn parse_input(input: &str) -> Result<Grid> {
+    Ok(Grid::parse_digits(input)?)
+}
+
+#[cfg(test)]
+mod day09_tests {
+    use super::{parse_input, part1, part2};
+
+    static TEST_INPUT: &str = r"2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 15);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 1134);
+    }
+}
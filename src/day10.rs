@@ -0,0 +1,218 @@
+//! Day 10: Syntax Scoring.
+
+use std::collections::VecDeque;
+
+use anyhow::Result;
+
+use crate::solution::{Params, Solution};
+
+/// The [`Solution`] for day 10.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Vec<String>> {
+        let lines: Vec<String> = input.lines().map(String::from).collect();
+        if lines.is_empty() {
+            anyhow::bail!("There are no lines to check");
+        }
+        for (i, line) in lines.iter().enumerate() {
+            if let Some((column, c)) = line
+                .chars()
+                .enumerate()
+                .find(|(_, c)| !"()[]{}<>".contains(*c))
+            {
+                anyhow::bail!(
+                    "Unexpected {:?} at line {}, column {}",
+                    c,
+                    i + 1,
+                    column + 1
+                );
+            }
+        }
+        Ok(lines)
+    }
+
+    fn part1(&self, input: &Vec<String>, _params: &Params) -> Result<u32> {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input: &Vec<String>, _params: &Params) -> Result<u64> {
+        Ok(part2(input))
+    }
+}
+
+/// The total syntax error score of the corrupted lines.
+pub fn part1(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .flat_map(|line| find_corrupted_char(line))
+        .map(|c| match c {
+            ')' => 3,
+            ']' => 57,
+            '}' => 1197,
+            '>' => 25137,
+            _ => unreachable!(),
+        })
+        .sum()
+}
+
+/// The middle of the completion scores of all the lines.
+pub fn part2(lines: &[String]) -> u64 {
+    let mut scores = lines
+        .iter()
+        .map(|line| calculate_completion_score(line))
+        .collect::<Vec<u64>>();
+    scores.sort_unstable();
+    scores[scores.len() / 2]
+}
+
+/// The first closing character that does not match its opening one, or has
+/// none, if any.
+///
+/// # Panics
+///
+/// If the line holds anything other than brackets.
+pub fn find_corrupted_char(line: &str) -> Option<char> {
+    let mut stack = VecDeque::new();
+    for c in line.chars() {
+        match c {
+            '[' | '(' | '{' | '<' => stack.push_back(c),
+            ']' | ')' | '}' | '>' => {
+                if stack.pop_back() != Some(find_opening_pair(c)) {
+                    return Some(c);
+                }
+            }
+            _ => unreachable!(),
+        };
+    }
+    None
+}
+
+/// The score of the characters that would close every bracket left open.
+///
+/// # Panics
+///
+/// If the line holds anything other than brackets.
+pub fn calculate_completion_score(line: &str) -> u64 {
+    let mut stack = VecDeque::new();
+    for c in line.chars() {
+        match c {
+            '[' | '(' | '{' | '<' => {
+                stack.push_back(c);
+            }
+            ']' | ')' | '}' | '>' => {
+                stack.pop_back();
+            }
+            _ => unreachable!(),
+        };
+    }
+    stack
+        .iter()
+        .rev()
+        .map(|c| find_closing_pair(*c))
+        .map(|c| match c {
+            ')' => 1,
+            ']' => 2,
+            '}' => 3,
+            '>' => 4,
+            _ => unreachable!(),
+        })
+        .fold(0, |acc, v| acc * 5 + v)
+}
+
+fn find_opening_pair(c: char) -> char {
+    match c {
+        ']' => '[',
+        ')' => '(',
+        '}' => '{',
+        '>' => '<',
+        _ => unreachable!(),
+    }
+}
+
+fn find_closing_pair(c: char) -> char {
+    match c {
+        '[' => ']',
+        '(' => ')',
+        '{' => '}',
+        '<' => '>',
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod day10_tests {
+    use super::{calculate_completion_score, find_corrupted_char, part1, part2, Day10};
+    use crate::solution::{Params, Solution};
+
+    static TEST_INPUT: &str = r"[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(
+            part1(&Day10.parse(TEST_INPUT, &Params::default()).unwrap()),
+            26397
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            part2(&Day10.parse(TEST_INPUT, &Params::default()).unwrap()),
+            288957
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let params = Params::default();
+        assert!(Day10.parse("", &params).is_err());
+        assert_eq!(
+            Day10.parse("()\n(abc", &params).unwrap_err().to_string(),
+            "Unexpected 'a' at line 2, column 2"
+        );
+    }
+
+    #[test]
+    fn test_find_corrupted_char() {
+        assert_eq!(
+            find_corrupted_char("{([(<{}[<>[]}>{[]{[(<()>").unwrap(),
+            '}'
+        );
+        assert_eq!(find_corrupted_char("[[<[([]))<([[{}[[()]]]").unwrap(), ')');
+        assert_eq!(find_corrupted_char("[{[{({}]{}}([{[{{{}}([]").unwrap(), ']');
+        assert_eq!(find_corrupted_char("[<(<(<(<{}))><([]([]()").unwrap(), ')');
+        assert_eq!(find_corrupted_char("<{([([[(<>()){}]>(<<{{").unwrap(), '>');
+    }
+
+    #[test]
+    fn test_calculate_completion_score() {
+        assert_eq!(
+            calculate_completion_score("[({(<(())[]>[[{[]{<()<>>"),
+            288957
+        );
+        assert_eq!(calculate_completion_score("[(()[<>])]({[<{<<[]>>("), 5566);
+        assert_eq!(
+            calculate_completion_score("(((({<>}<{<{<>}{[]{[]{}"),
+            1480781
+        );
+        assert_eq!(
+            calculate_completion_score("{<[[]]>}<{[{[{[]{()[[[]"),
+            995444
+        );
+        assert_eq!(calculate_completion_score("<{([{{}}[<[[[<>{}]]]>[]]"), 294);
+    }
+}
@@ -0,0 +1,154 @@
+//! Day 11: Dumbo Octopus.
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::{
+    cancel,
+    common::{Grid, Neighborhood},
+    solution::{Param, Params, Solution},
+};
+
+/// The [`Solution`] for day 11.
+pub struct Day11;
+
+static PARAMS: &[Param] = &[Param::new(
+    "steps",
+    "100",
+    "Number of steps simulated in part 1",
+)];
+
+impl Solution for Day11 {
+    type Input = Grid;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn params(&self) -> &'static [Param] {
+        PARAMS
+    }
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Grid> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Grid, params: &Params) -> Result<u32> {
+        part1(input, params.get("steps")?)
+    }
+
+    fn part2(&self, input: &Grid, _params: &Params) -> Result<u32> {
+        part2(input)
+    }
+}
+
+/// The number of flashes in the first `steps` steps.
+pub fn part1(grid: &Grid, steps: u32) -> Result<u32> {
+    let mut grid = grid.clone();
+    let mut total = 0;
+    for step in 1..=steps {
+        cancel::check()?;
+        let flashes = simulate(&mut grid);
+        tracing::debug!(step, flashes);
+        total += flashes;
+    }
+    Ok(total)
+}
+
+/// The first step during which every octopus flashes. Grids that never
+/// synchronise keep it going until the solver is cancelled.
+pub fn part2(grid: &Grid) -> Result<u32> {
+    let mut grid = grid.clone();
+    let target_flash_count = (grid.max_x() * grid.max_y()) as u32;
+    let mut counter = 0;
+
+    loop {
+        cancel::check()?;
+        let flashes = simulate(&mut grid);
+        tracing::debug!(step = counter + 1, flashes);
+        if flashes == target_flash_count {
+            break;
+        }
+        counter += 1;
+    }
+    Ok(counter + 1)
+}
+
+/// Runs one step in place, returning how many octopuses flashed.
+pub fn simulate(grid: &mut Grid) -> u32 {
+    simulate_with(grid, &Neighborhood::moore(1))
+}
+
+/// Like [`simulate`], a flash raising the energy of every octopus in
+/// `neighborhood` around it rather than just the eight adjacent ones.
+pub fn simulate_with(grid: &mut Grid, neighborhood: &Neighborhood) -> u32 {
+    let mut flash_count = 0;
+
+    let points: Vec<_> = grid.points().collect();
+    for &point in &points {
+        grid[point] += 1;
+    }
+    let mut flashed_octos = HashSet::new();
+
+    loop {
+        let mut flashed = false;
+        for &point in &points {
+            if grid[point] > 9 && !flashed_octos.contains(&point) {
+                flashed_octos.insert(point);
+                flashed = true;
+                flash_count += 1;
+                for adjacent in grid.iter_neighborhood(point, neighborhood) {
+                    grid[adjacent] += 1;
+                }
+            }
+        }
+        if !flashed {
+            break;
+        }
+    }
+    for point in flashed_octos {
+        grid[point] = 0;
+    }
+    flash_count
+}
+
+/// Reads the energy levels.
+pub fn parse_input(input: &str) -> Result<Grid> {
+    Ok(Grid::parse_digits(input)?)
+}
+
+#[cfg(test)]
+mod day11_tests {
+    use super::{parse_input, part1, part2, simulate_with};
+    use crate::common::Neighborhood;
+
+    static TEST_INPUT: &str = r"5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap(), 10).unwrap(), 204);
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap(), 100).unwrap(), 1656);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()).unwrap(), 195);
+    }
+
+    #[test]
+    fn test_simulate_with() {
+        // the middle octopus only flashes if the others' flashes reach it
+        let mut grid = parse_input("989").unwrap();
+        assert_eq!(simulate_with(&mut grid, &Neighborhood::von_neumann(1)), 3);
+        let mut grid = parse_input("989").unwrap();
+        assert_eq!(simulate_with(&mut grid, &Neighborhood::from_offsets([])), 2);
+    }
+}
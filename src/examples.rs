@@ -0,0 +1,120 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use toml::Value;
+
+use crate::{
+    input,
+    solution::{self, Params},
+    verify::{value_to_string, Check},
+};
+
+/// The sample cases for one day, read from `examples/dayNN.toml`.
+#[derive(Deserialize)]
+struct Examples {
+    case: Vec<Case>,
+}
+
+/// A sample input, relative to the examples directory, with the parameters to
+/// run it with and the expected answers.
+#[derive(Deserialize)]
+struct Case {
+    input: PathBuf,
+    #[serde(default)]
+    params: BTreeMap<String, Value>,
+    part1: Option<Value>,
+    part2: Option<Value>,
+}
+
+/// The `examples` directory at the root of this crate.
+pub fn default_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")
+}
+
+/// Runs every case in every `dayNN.toml` inside `dir`. Each check is returned
+/// with a label naming the file and the case it came from.
+pub fn run_examples(dir: &Path) -> Result<Vec<(String, Check)>> {
+    let mut files: Vec<(u8, PathBuf)> = fs::read_dir(dir)
+        .with_context(|| format!("Could not read examples from `{}`", dir.display()))?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|e| e == "toml"))
+        .filter_map(|path| {
+            let day = path
+                .file_stem()?
+                .to_str()?
+                .strip_prefix("day")?
+                .parse()
+                .ok()?;
+            Some((day, path))
+        })
+        .collect();
+    files.sort();
+
+    let mut checks = Vec::new();
+    for (day, path) in files {
+        let solver = solution::get(day)?.solver;
+        let content = fs::read_to_string(&path)?;
+        let examples: Examples = toml::from_str(&content)
+            .with_context(|| format!("Invalid examples in `{}`", path.display()))?;
+
+        for (i, case) in examples.case.iter().enumerate() {
+            let label = format!("{} case {}", path.display(), i + 1);
+            let mut params = Params::default();
+            for (name, value) in &case.params {
+                params.set(name, &value_to_string(value));
+            }
+            solution::check_params([solution::get(day)?], &params)
+                .with_context(|| label.clone())?;
+
+            let input = input::read(day, &dir.join(&case.input))?;
+            let parsed = solver
+                .parse(&input, &params)
+                .with_context(|| label.clone())?;
+            for (part, expected) in [(1, &case.part1), (2, &case.part2)] {
+                if let Some(expected) = expected {
+                    let actual = solver
+                        .part(&parsed, part, &params)
+                        .with_context(|| label.clone())?;
+                    let expected = Some(value_to_string(expected));
+                    checks.push((
+                        label.clone(),
+                        Check::new(day, part, expected, actual.to_string()),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod examples_tests {
+    use super::{default_dir, run_examples};
+
+    #[test]
+    fn test_examples() {
+        let checks = run_examples(&default_dir()).unwrap();
+        let failures: Vec<String> = checks
+            .iter()
+            .filter(|(_, check)| check.is_mismatch())
+            .map(|(label, check)| {
+                format!(
+                    "{} part {}: expected {}, got {}",
+                    label,
+                    check.part,
+                    check.expected.as_deref().unwrap_or_default(),
+                    check.actual.as_deref().unwrap_or_else(|error| error)
+                )
+            })
+            .collect();
+        assert!(!checks.is_empty());
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
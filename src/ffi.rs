@@ -0,0 +1,187 @@
+//! C bindings for the solvers, declared in `include/advent_of_code_2021.h`.
+
+use std::{
+    ffi::{c_char, CString},
+    panic, slice, str,
+};
+
+use crate::solution::{self, Params};
+
+/// What `aoc_solve` managed to do.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    /// The answer was written out.
+    Ok = 0,
+    /// A required pointer was null.
+    NullPointer = 1,
+    /// There is no solution for the day.
+    UnknownDay = 2,
+    /// The part is not 1 or 2.
+    UnknownPart = 3,
+    /// The input is not UTF-8, or the day could not parse it.
+    ParseFailed = 4,
+    /// The input parsed but the part could not be solved.
+    SolveFailed = 5,
+    /// The solver panicked.
+    Panicked = 6,
+}
+
+/// Solves `part` of `day` against the `input_len` bytes at `input`, using the
+/// default parameters. On success `*answer` is set to the answer, otherwise to
+/// a message describing the failure. Either way, unless the status is
+/// `NullPointer`, the string must be released with `aoc_free_string`.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, or may be null when
+/// `input_len` is 0. `answer` must point to writable memory for a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    answer: *mut *mut c_char,
+) -> AocStatus {
+    if answer.is_null() || (input.is_null() && input_len > 0) {
+        return AocStatus::NullPointer;
+    }
+    let input = match input_len {
+        0 => &[],
+        _ => slice::from_raw_parts(input, input_len),
+    };
+    let (status, text) = match panic::catch_unwind(|| solve(day, part, input)) {
+        Ok(Ok(text)) => (AocStatus::Ok, text),
+        Ok(Err(failure)) => failure,
+        Err(_) => (AocStatus::Panicked, "The solver panicked".to_string()),
+    };
+    *answer = into_c_string(text);
+    status
+}
+
+/// Releases a string returned by `aoc_solve`. Does nothing when given null.
+///
+/// # Safety
+///
+/// `string` must come from `aoc_solve` and not have been freed already.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free_string(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+fn solve(day: u8, part: u8, input: &[u8]) -> Result<String, (AocStatus, String)> {
+    let day = solution::get(day).map_err(|err| (AocStatus::UnknownDay, err.to_string()))?;
+    if !(1..=2).contains(&part) {
+        return Err((AocStatus::UnknownPart, format!("There is no part {}", part)));
+    }
+    let input = str::from_utf8(input).map_err(|err| {
+        (
+            AocStatus::ParseFailed,
+            format!("Input is not UTF-8: {}", err),
+        )
+    })?;
+    let params = Params::default();
+    let parsed = day
+        .solver
+        .parse(input, &params)
+        .map_err(|err| (AocStatus::ParseFailed, format!("{:#}", err)))?;
+    let answer = day
+        .solver
+        .part(&parsed, part, &params)
+        .map_err(|err| (AocStatus::SolveFailed, format!("{:#}", err)))?;
+    Ok(answer.to_string())
+}
+
+/// C strings end at the first NUL, so any inside `text` are dropped.
+fn into_c_string(text: String) -> *mut c_char {
+    CString::new(text.replace('\0', ""))
+        .unwrap_or_default()
+        .into_raw()
+}
+
+#[cfg(test)]
+mod ffi_tests {
+    use std::{
+        env,
+        ffi::{c_char, CStr},
+        fs,
+        path::Path,
+        ptr,
+    };
+
+    use super::{aoc_free_string, aoc_solve, AocStatus};
+
+    fn call(day: u8, part: u8, input: &[u8]) -> (AocStatus, String) {
+        let mut answer: *mut c_char = ptr::null_mut();
+        unsafe {
+            let status = aoc_solve(day, part, input.as_ptr(), input.len(), &mut answer);
+            let text = CStr::from_ptr(answer).to_str().unwrap().to_string();
+            aoc_free_string(answer);
+            (status, text)
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            call(6, 1, b"3,4,3,1,2"),
+            (AocStatus::Ok, "5934".to_string())
+        );
+        assert_eq!(
+            call(6, 2, b"3,4,3,1,2\n"),
+            (AocStatus::Ok, "26984457539".to_string())
+        );
+        assert_eq!(call(30, 1, b"").0, AocStatus::UnknownDay);
+        assert_eq!(call(6, 3, b"3,4").0, AocStatus::UnknownPart);
+        assert_eq!(call(6, 1, b"3,x").0, AocStatus::ParseFailed);
+        assert_eq!(call(6, 1, b"\xff").0, AocStatus::ParseFailed);
+        assert_eq!(call(2, 1, b"hello world").0, AocStatus::ParseFailed);
+        assert_eq!(call(4, 1, b"1,2").0, AocStatus::SolveFailed);
+        assert_eq!(
+            call(9, 1, b"12\n3x"),
+            (
+                AocStatus::ParseFailed,
+                "Unexpected 'x' at line 2, column 2".to_string()
+            )
+        );
+        assert_eq!(
+            call(10, 1, b"(x"),
+            (
+                AocStatus::ParseFailed,
+                "Unexpected 'x' at line 1, column 2".to_string()
+            )
+        );
+        unsafe {
+            assert_eq!(
+                aoc_solve(6, 1, ptr::null(), 0, ptr::null_mut()),
+                AocStatus::NullPointer
+            );
+            aoc_free_string(ptr::null_mut());
+        }
+    }
+
+    /// Fails when the checked in header is out of date, rewriting it when
+    /// `UPDATE_HEADER` is set.
+    #[test]
+    fn test_header() {
+        let root = env!("CARGO_MANIFEST_DIR");
+        let path = Path::new(root).join("include/advent_of_code_2021.h");
+        let mut generated = Vec::new();
+        cbindgen::generate(root)
+            .expect("Could not generate the header")
+            .write(&mut generated);
+        let generated = String::from_utf8(generated).unwrap();
+        if env::var_os("UPDATE_HEADER").is_some() {
+            fs::write(&path, &generated).unwrap();
+        }
+        assert_eq!(
+            fs::read_to_string(&path).unwrap_or_default(),
+            generated,
+            "`{}` is out of date, run the tests with UPDATE_HEADER=1",
+            path.display()
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+/// Environment variable overriding the directory puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Locates the input file for each day inside a directory.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Inputs {
+        Inputs { dir: dir.into() }
+    }
+
+    /// Uses `$AOC_INPUT_DIR` when it is set, otherwise the `input` directory of
+    /// this crate, so it doesn't matter where the program is run from.
+    pub fn from_env() -> Inputs {
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) => Inputs::new(dir),
+            None => Inputs::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("input")),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{:02}.txt", day))
+    }
+
+    pub fn load(&self, day: u8) -> Result<String> {
+        read(day, &self.path(day))
+    }
+}
+
+impl Default for Inputs {
+    fn default() -> Self {
+        Inputs::from_env()
+    }
+}
+
+/// Reads the input for `day` from `path`, or from stdin when `path` is `-`.
+pub fn read(day: u8, path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .with_context(|| format!("Could not read input for day {} from stdin", day))?;
+        return Ok(input);
+    }
+    fs::read_to_string(path).with_context(|| {
+        format!(
+            "Could not read input for day {} from `{}`",
+            day,
+            path.display()
+        )
+    })
+}
@@ -1,27 +1,423 @@
-#[allow(clippy::redundant_static_lifetimes)]
+pub mod bench;
+pub mod cancel;
+pub mod client;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod examples;
+pub mod ffi;
+pub mod input;
+pub mod profile;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod serve;
+pub mod solution;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+
 pub mod common {
+    use std::{
+        error::Error,
+        fmt,
+        ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+    };
+
+    /// A position, or the offset between two, on an unbounded plane. Points
+    /// name grid cells the way indices do, `x` being the row and `y` the
+    /// column.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    impl Point {
+        pub const ORIGIN: Point = Point::new(0, 0);
+
+        pub const fn new(x: i32, y: i32) -> Point {
+            Point { x, y }
+        }
+
+        /// The point for the grid cell at `(x, y)`.
+        ///
+        /// # Panics
+        ///
+        /// If a coordinate doesn't fit in an `i32`.
+        pub fn from_index((x, y): (usize, usize)) -> Point {
+            let coordinate = |c: usize| i32::try_from(c).expect("Grids are smaller than i32::MAX");
+            Point::new(coordinate(x), coordinate(y))
+        }
+
+        /// The grid index of the point, or `None` when a coordinate is negative.
+        pub fn to_index(self) -> Option<(usize, usize)> {
+            Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+        }
+
+        pub fn manhattan_distance(self, other: Point) -> u32 {
+            self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+        }
+
+        /// The next point in `direction`.
+        pub fn step(self, direction: Direction) -> Point {
+            self + direction.offset()
+        }
+    }
+
+    impl From<(i32, i32)> for Point {
+        fn from((x, y): (i32, i32)) -> Point {
+            Point::new(x, y)
+        }
+    }
+
+    impl fmt::Display for Point {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "({}, {})", self.x, self.y)
+        }
+    }
+
+    impl Add for Point {
+        type Output = Point;
+
+        fn add(self, other: Point) -> Point {
+            Point::new(self.x + other.x, self.y + other.y)
+        }
+    }
+
+    impl Sub for Point {
+        type Output = Point;
+
+        fn sub(self, other: Point) -> Point {
+            Point::new(self.x - other.x, self.y - other.y)
+        }
+    }
+
+    impl AddAssign for Point {
+        fn add_assign(&mut self, other: Point) {
+            *self = *self + other;
+        }
+    }
+
+    impl SubAssign for Point {
+        fn sub_assign(&mut self, other: Point) {
+            *self = *self - other;
+        }
+    }
+
+    impl Mul<i32> for Point {
+        type Output = Point;
+
+        fn mul(self, factor: i32) -> Point {
+            Point::new(self.x * factor, self.y * factor)
+        }
+    }
+
+    impl Neg for Point {
+        type Output = Point;
+
+        fn neg(self) -> Point {
+            Point::new(-self.x, -self.y)
+        }
+    }
+
+    /// A compass direction on a grid, North being towards the first row and
+    /// East towards the last column.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Direction {
+        North,
+        NorthEast,
+        East,
+        SouthEast,
+        South,
+        SouthWest,
+        West,
+        NorthWest,
+    }
+
+    impl Direction {
+        /// The four directions sharing an edge, clockwise from North.
+        pub const CARDINAL: [Direction; 4] = [
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ];
+
+        /// All eight directions, clockwise from North.
+        pub const ALL: [Direction; 8] = [
+            Direction::North,
+            Direction::NorthEast,
+            Direction::East,
+            Direction::SouthEast,
+            Direction::South,
+            Direction::SouthWest,
+            Direction::West,
+            Direction::NorthWest,
+        ];
+
+        /// The offset of one step this way.
+        pub fn offset(self) -> Point {
+            match self {
+                Direction::North => Point::new(-1, 0),
+                Direction::NorthEast => Point::new(-1, 1),
+                Direction::East => Point::new(0, 1),
+                Direction::SouthEast => Point::new(1, 1),
+                Direction::South => Point::new(1, 0),
+                Direction::SouthWest => Point::new(1, -1),
+                Direction::West => Point::new(0, -1),
+                Direction::NorthWest => Point::new(-1, -1),
+            }
+        }
+
+        pub fn is_diagonal(self) -> bool {
+            (self as usize) % 2 == 1
+        }
+
+        /// Turns clockwise by `eighths` of a full turn, anticlockwise when
+        /// negative.
+        pub fn rotate(self, eighths: i32) -> Direction {
+            Direction::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+        }
+
+        /// Turns 90 degrees clockwise.
+        pub fn turn_right(self) -> Direction {
+            self.rotate(2)
+        }
+
+        /// Turns 90 degrees anticlockwise.
+        pub fn turn_left(self) -> Direction {
+            self.rotate(-2)
+        }
+
+        pub fn opposite(self) -> Direction {
+            self.rotate(4)
+        }
+    }
+
+    /// Which cells count as next to a cell, as offsets from it. Without
+    /// wrapping, neighbors outside the grid are left out; with it, the grid
+    /// is a torus and they come back in on the opposite edge.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Neighborhood {
+        offsets: Vec<Point>,
+        wrapping: bool,
+    }
+
+    impl Neighborhood {
+        /// The cells at most `radius` steps away along rows and columns, the
+        /// four sharing an edge at radius 1.
+        ///
+        /// # Panics
+        ///
+        /// If `radius` doesn't fit in an `i32`.
+        pub fn von_neumann(radius: u32) -> Neighborhood {
+            Neighborhood::within(radius, |offset| {
+                offset.manhattan_distance(Point::ORIGIN) <= radius
+            })
+        }
+
+        /// The cells in the square reaching `radius` steps out, the eight
+        /// around a cell at radius 1.
+        ///
+        /// # Panics
+        ///
+        /// If `radius` doesn't fit in an `i32`.
+        pub fn moore(radius: u32) -> Neighborhood {
+            Neighborhood::within(radius, |_| true)
+        }
+
+        /// Any other shape, such as a knight's moves.
+        pub fn from_offsets(offsets: impl IntoIterator<Item = Point>) -> Neighborhood {
+            Neighborhood {
+                offsets: offsets.into_iter().collect(),
+                wrapping: false,
+            }
+        }
+
+        /// The offsets in the square around `radius` that `keep` accepts,
+        /// other than the cell itself.
+        fn within(radius: u32, keep: impl Fn(Point) -> bool) -> Neighborhood {
+            let radius = i32::try_from(radius).expect("Radii are smaller than i32::MAX");
+            let square =
+                (-radius..=radius).flat_map(|x| (-radius..=radius).map(move |y| Point::new(x, y)));
+            Neighborhood::from_offsets(
+                square.filter(|&offset| offset != Point::ORIGIN && keep(offset)),
+            )
+        }
+
+        /// The same neighborhood wrapping around the edges of the grid. On
+        /// grids smaller than the neighborhood a cell can come up more than
+        /// once, or be its own neighbor.
+        pub fn wrapping(self) -> Neighborhood {
+            Neighborhood {
+                wrapping: true,
+                ..self
+            }
+        }
+
+        pub fn offsets(&self) -> &[Point] {
+            &self.offsets
+        }
+
+        pub fn is_wrapping(&self) -> bool {
+            self.wrapping
+        }
+    }
+
+    /// Why some text could not be turned into a [`Grid`]. Lines and columns
+    /// count from 1.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum ParseGridError {
+        InvalidChar {
+            line: usize,
+            column: usize,
+            found: char,
+        },
+        /// A line whose length differs from the first one's.
+        Ragged {
+            line: usize,
+            length: usize,
+            expected: usize,
+        },
+    }
+
+    impl fmt::Display for ParseGridError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                ParseGridError::InvalidChar {
+                    line,
+                    column,
+                    found,
+                } => write!(
+                    f,
+                    "Unexpected {:?} at line {}, column {}",
+                    found, line, column
+                ),
+                ParseGridError::Ragged {
+                    line,
+                    length,
+                    expected,
+                } => write!(
+                    f,
+                    "Line {} has {} cells where the first line has {}",
+                    line, length, expected
+                ),
+            }
+        }
+    }
+
+    impl Error for ParseGridError {}
+
+    /// A rectangle of cells stored row after row in one `Vec`, indexed as
+    /// `grid[(x, y)]` with `x` the row and `y` the column. Defaults to `u32`
+    /// cells, for the digit maps most days use.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Grid<T = u32> {
+        cells: Vec<T>,
+        rows: usize,
+        columns: usize,
+    }
+
+    impl<T> Grid<T> {
+        /// Builds a grid from its rows, which must all be the same length.
+        pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, ParseGridError> {
+            let columns = rows.first().map_or(0, |row| row.len());
+            if let Some((x, row)) = rows
+                .iter()
+                .enumerate()
+                .find(|(_, row)| row.len() != columns)
+            {
+                return Err(ParseGridError::Ragged {
+                    line: x + 1,
+                    length: row.len(),
+                    expected: columns,
+                });
+            }
+            let mut cells = Vec::with_capacity(rows.len() * columns);
+            let count = rows.len();
+            for row in rows {
+                cells.extend(row);
+            }
+            Ok(Grid {
+                cells,
+                rows: count,
+                columns,
+            })
+        }
+
+        /// Reads a grid with one row per line, turning every character into a
+        /// cell with `f`, which returns `None` for characters that don't belong.
+        pub fn parse_with(
+            input: &str,
+            mut f: impl FnMut(char) -> Option<T>,
+        ) -> Result<Grid<T>, ParseGridError> {
+            let mut cells = Vec::with_capacity(input.len());
+            let mut rows = 0;
+            let mut columns = 0;
+            for (x, line) in input.lines().enumerate() {
+                let start = cells.len();
+                for (y, c) in line.chars().enumerate() {
+                    let cell = f(c).ok_or(ParseGridError::InvalidChar {
+                        line: x + 1,
+                        column: y + 1,
+                        found: c,
+                    })?;
+                    cells.push(cell);
+                }
+                let length = cells.len() - start;
+                if x == 0 {
+                    columns = length;
+                } else if length != columns {
+                    return Err(ParseGridError::Ragged {
+                        line: x + 1,
+                        length,
+                        expected: columns,
+                    });
+                }
+                rows += 1;
+            }
+            Ok(Grid {
+                cells,
+                rows,
+                columns,
+            })
+        }
+
+        pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+            (x < self.rows && y < self.columns).then(|| &self.cells[x * self.columns + y])
+        }
+
+        pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+            (x < self.rows && y < self.columns).then(|| &mut self.cells[x * self.columns + y])
+        }
 
-    const OFFSETS: &'static [(i32, i32)] = &[(0, 1), (0, -1), (1, 0), (-1, 0)];
-    const OFFSETS_WITH_DIAGONALS: &'static [(i32, i32)] = &[
-        (0, 1),
-        (0, -1),
-        (1, 0),
-        (-1, 0),
-        (1, 1),
-        (-1, -1),
-        (1, -1),
-        (-1, 1),
-    ];
-    pub struct Grid {
-        pub cells: Vec<Vec<u32>>,
-    }
-
-    impl Grid {
-        pub fn iter_cells(&self) -> impl Iterator<Item = ((usize, usize), &u32)> {
+        pub fn row(&self, x: usize) -> &[T] {
+            &self.cells[x * self.columns..(x + 1) * self.columns]
+        }
+
+        pub fn row_mut(&mut self, x: usize) -> &mut [T] {
+            &mut self.cells[x * self.columns..(x + 1) * self.columns]
+        }
+
+        pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+            (0..self.rows).map(|x| self.row(x))
+        }
+
+        pub fn iter_cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+            let columns = self.columns;
             self.cells
                 .iter()
                 .enumerate()
-                .flat_map(|(x, row)| row.iter().enumerate().map(move |(y, col)| ((x, y), col)))
+                .map(move |(i, cell)| ((i / columns, i % columns), cell))
         }
 
         pub fn iter_adjacent(
@@ -30,33 +426,315 @@ pub mod common {
             y: usize,
             include_diagonals: bool,
         ) -> impl Iterator<Item = (usize, usize)> {
-            let max_x = self.max_x() as i32;
-            let max_y = self.max_y() as i32;
-            let offsets = if include_diagonals {
-                OFFSETS_WITH_DIAGONALS
+            let directions: &'static [Direction] = if include_diagonals {
+                &Direction::ALL
             } else {
-                OFFSETS
+                &Direction::CARDINAL
             };
-            offsets
+            self.neighbors(Point::from_index((x, y)), directions)
+                .filter_map(Point::to_index)
+        }
+
+        pub fn contains(&self, point: Point) -> bool {
+            point
+                .to_index()
+                .is_some_and(|(x, y)| x < self.rows && y < self.columns)
+        }
+
+        /// The cell at `point`, like [`Grid::get`].
+        pub fn at(&self, point: Point) -> Option<&T> {
+            let (x, y) = point.to_index()?;
+            self.get(x, y)
+        }
+
+        pub fn at_mut(&mut self, point: Point) -> Option<&mut T> {
+            let (x, y) = point.to_index()?;
+            self.get_mut(x, y)
+        }
+
+        /// The neighbors of `point` in `neighborhood`. Like
+        /// [`Grid::neighbors`], this doesn't borrow the grid.
+        pub fn iter_neighborhood<'a>(
+            &self,
+            point: Point,
+            neighborhood: &'a Neighborhood,
+        ) -> impl Iterator<Item = Point> + 'a {
+            let (rows, columns) = (self.rows as i32, self.columns as i32);
+            let wrapping = neighborhood.wrapping && rows > 0 && columns > 0;
+            neighborhood.offsets.iter().filter_map(move |&offset| {
+                let next = point + offset;
+                if wrapping {
+                    Some(Point::new(
+                        next.x.rem_euclid(rows),
+                        next.y.rem_euclid(columns),
+                    ))
+                } else {
+                    (next.x >= 0 && next.x < rows && next.y >= 0 && next.y < columns)
+                        .then_some(next)
+                }
+            })
+        }
+
+        /// Every point of the grid, row after row.
+        pub fn points(&self) -> impl Iterator<Item = Point> {
+            let columns = self.columns;
+            (0..self.cells.len()).map(move |i| Point::from_index((i / columns, i % columns)))
+        }
+
+        /// Every cell with its point, row after row.
+        pub fn iter_points(&self) -> impl Iterator<Item = (Point, &T)> {
+            self.points().zip(&self.cells)
+        }
+
+        /// The points one step from `point` in each of `directions` that are
+        /// inside the grid. Doesn't borrow the grid, so the cells can be
+        /// updated while iterating.
+        pub fn neighbors<'a>(
+            &self,
+            point: Point,
+            directions: &'a [Direction],
+        ) -> impl Iterator<Item = Point> + 'a {
+            let (rows, columns) = (self.rows, self.columns);
+            directions
                 .iter()
-                .map(move |(dx, dy)| ((x as i32) + dx, (y as i32) + dy))
-                .filter(move |(cx, cy)| (*cx >= 0 && *cx < max_x) && (*cy >= 0 && *cy < max_y))
-                .map(|(cx, cy)| (cx as usize, cy as usize))
+                .map(move |&direction| point.step(direction))
+                .filter(move |next| {
+                    next.to_index()
+                        .is_some_and(|(x, y)| x < rows && y < columns)
+                })
         }
 
+        /// The number of rows.
         pub fn max_x(&self) -> usize {
-            self.cells.len()
+            self.rows
         }
 
+        /// The number of columns.
         pub fn max_y(&self) -> usize {
-            self.cells.get(0).map_or(0, |v| v.len())
+            self.columns
         }
 
+        /// A grid of the same shape holding `f` of every cell.
+        pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+            Grid {
+                cells: self.cells.iter().map(f).collect(),
+                rows: self.rows,
+                columns: self.columns,
+            }
+        }
+    }
+
+    impl Grid<u32> {
+        /// Reads a grid of single digits, one row per line.
+        pub fn parse_digits(input: &str) -> Result<Grid<u32>, ParseGridError> {
+            Grid::parse_with(input, |c| c.to_digit(10))
+        }
+    }
+
+    impl Grid<char> {
+        /// Reads a grid of characters, one row per line.
+        pub fn parse_chars(input: &str) -> Result<Grid<char>, ParseGridError> {
+            Grid::parse_with(input, Some)
+        }
+    }
+
+    impl<T: fmt::Debug> Grid<T> {
         pub fn print(&self) {
-            for row in &self.cells {
+            for row in self.rows() {
                 println!("{:?}", row);
             }
             println!();
         }
     }
+
+    impl<T> Index<(usize, usize)> for Grid<T> {
+        type Output = T;
+
+        fn index(&self, (x, y): (usize, usize)) -> &T {
+            self.get(x, y).unwrap_or_else(|| {
+                panic!(
+                    "({}, {}) is outside the {}x{} grid",
+                    x, y, self.rows, self.columns
+                )
+            })
+        }
+    }
+
+    impl<T> Index<Point> for Grid<T> {
+        type Output = T;
+
+        fn index(&self, point: Point) -> &T {
+            self.at(point).unwrap_or_else(|| {
+                panic!(
+                    "{} is outside the {}x{} grid",
+                    point, self.rows, self.columns
+                )
+            })
+        }
+    }
+
+    impl<T> IndexMut<Point> for Grid<T> {
+        fn index_mut(&mut self, point: Point) -> &mut T {
+            let (rows, columns) = (self.rows, self.columns);
+            self.at_mut(point)
+                .unwrap_or_else(|| panic!("{} is outside the {}x{} grid", point, rows, columns))
+        }
+    }
+
+    impl<T> IndexMut<(usize, usize)> for Grid<T> {
+        fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+            let (rows, columns) = (self.rows, self.columns);
+            self.get_mut(x, y).unwrap_or_else(|| {
+                panic!("({}, {}) is outside the {}x{} grid", x, y, rows, columns)
+            })
+        }
+    }
+
+    #[cfg(test)]
+    mod common_tests {
+        use super::{Direction, Grid, Neighborhood, ParseGridError, Point};
+
+        #[test]
+        fn test_map() {
+            let grid =
+                Grid::from_rows(vec![vec!['#', '.'], vec!['.', '.'], vec!['#', '#']]).unwrap();
+            let walls = grid.map(|&c| c == '#');
+            assert_eq!(walls.max_x(), 3);
+            assert_eq!(walls.max_y(), 2);
+            assert_eq!(walls.iter_cells().filter(|(_, &wall)| wall).count(), 3);
+            assert_eq!(walls.row(2), [true, true]);
+        }
+
+        #[test]
+        fn test_from_rows() {
+            let mut grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+            assert_eq!((grid.max_x(), grid.max_y()), (2, 3));
+            assert_eq!(grid[(1, 0)], 4);
+            grid[(0, 2)] = 7;
+            assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 7], [4, 5, 6]]);
+            assert_eq!(grid.get(2, 0), None);
+            assert_eq!(grid.get(0, 3), None);
+
+            assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+            let empty: Grid<u32> = Grid::from_rows(vec![]).unwrap();
+            assert_eq!((empty.max_x(), empty.max_y()), (0, 0));
+            assert_eq!(empty.rows().count(), 0);
+        }
+
+        #[test]
+        fn test_parse() {
+            let grid = Grid::parse_digits("123\r\n456\r\n").unwrap();
+            assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+            assert_eq!(Grid::parse_chars("#.\n.#").unwrap()[(1, 1)], '#');
+            let walls = Grid::parse_with("#.\n.#", |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            })
+            .unwrap();
+            assert!(walls[(0, 0)] && !walls[(0, 1)]);
+
+            assert_eq!(
+                Grid::parse_digits("12\n3x"),
+                Err(ParseGridError::InvalidChar {
+                    line: 2,
+                    column: 2,
+                    found: 'x'
+                })
+            );
+            assert_eq!(
+                Grid::parse_digits("123\n12\n").unwrap_err().to_string(),
+                "Line 2 has 2 cells where the first line has 3"
+            );
+            assert_eq!(Grid::parse_digits("").unwrap().max_x(), 0);
+        }
+
+        #[test]
+        fn test_point() {
+            let a = Point::new(1, -2);
+            let b = Point::from((4, 2));
+            assert_eq!(a + b, Point::new(5, 0));
+            assert_eq!(b - a, Point::new(3, 4));
+            assert_eq!(-a * 2, Point::new(-2, 4));
+            assert_eq!(a.manhattan_distance(b), 7);
+            assert_eq!(b.manhattan_distance(Point::ORIGIN), 6);
+            assert_eq!(a.to_index(), None);
+            assert_eq!(b.to_index(), Some((4, 2)));
+            assert_eq!(Point::from_index((4, 2)), b);
+            assert_eq!(Point::ORIGIN.step(Direction::SouthWest), Point::new(1, -1));
+        }
+
+        #[test]
+        fn test_direction() {
+            assert_eq!(Direction::North.turn_right(), Direction::East);
+            assert_eq!(Direction::North.turn_left(), Direction::West);
+            assert_eq!(Direction::NorthWest.rotate(1), Direction::North);
+            assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+            for direction in Direction::ALL {
+                assert_eq!(direction.opposite().offset(), -direction.offset());
+                assert_eq!(direction.turn_left().turn_right(), direction);
+                assert_eq!(
+                    direction.is_diagonal(),
+                    !Direction::CARDINAL.contains(&direction)
+                );
+            }
+        }
+
+        #[test]
+        fn test_points() {
+            let mut grid = Grid::parse_digits("123\n456").unwrap();
+            let corner = Point::new(1, 2);
+            assert_eq!(grid[corner], 6);
+            grid[corner] = 0;
+            assert_eq!(grid[(1, 2)], 0);
+            assert!(grid.contains(corner) && !grid.contains(Point::new(2, 0)));
+            assert_eq!(grid.at(Point::new(0, -1)), None);
+            assert_eq!(grid.iter_points().nth(4), Some((Point::new(1, 1), &5)));
+            let mut neighbors: Vec<Point> = grid.neighbors(corner, &Direction::ALL).collect();
+            neighbors.sort();
+            assert_eq!(
+                neighbors,
+                [Point::new(0, 1), Point::new(0, 2), Point::new(1, 1)]
+            );
+        }
+
+        #[test]
+        fn test_neighborhood() {
+            assert_eq!(Neighborhood::von_neumann(1).offsets().len(), 4);
+            assert_eq!(Neighborhood::von_neumann(2).offsets().len(), 12);
+            assert_eq!(Neighborhood::moore(1).offsets().len(), 8);
+            assert_eq!(Neighborhood::moore(2).offsets().len(), 24);
+            assert!(Neighborhood::moore(0).offsets().is_empty());
+            for direction in Direction::CARDINAL {
+                assert!(Neighborhood::von_neumann(1)
+                    .offsets()
+                    .contains(&direction.offset()));
+            }
+
+            let grid = Grid::parse_digits("123\n456\n789").unwrap();
+            let corner = Point::ORIGIN;
+            let around = |neighborhood: &Neighborhood| {
+                let mut points: Vec<Point> = grid.iter_neighborhood(corner, neighborhood).collect();
+                points.sort();
+                points
+            };
+            assert_eq!(
+                around(&Neighborhood::von_neumann(1)),
+                [Point::new(0, 1), Point::new(1, 0)]
+            );
+            assert_eq!(
+                around(&Neighborhood::von_neumann(1).wrapping()),
+                [
+                    Point::new(0, 1),
+                    Point::new(0, 2),
+                    Point::new(1, 0),
+                    Point::new(2, 0)
+                ]
+            );
+            assert_eq!(around(&Neighborhood::moore(1).wrapping()).len(), 8);
+            let knight =
+                Neighborhood::from_offsets([(1, 2), (2, 1), (-1, 2), (-2, 1)].map(Point::from));
+            assert_eq!(around(&knight), [Point::new(1, 2), Point::new(2, 1)]);
+        }
+    }
 }
@@ -1,3 +1,442 @@
-fn main() {
-    println!("Hello, world!");
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2021::{
+    bench::{self, Baseline},
+    cancel,
+    client::{Client, Fetched},
+    input::{self, Inputs},
+    profile, report, runner, scaffold, serve,
+    solution::{self, Params},
+    submit::{self, History},
+    verify::{self, Answers},
+    watch,
+};
+use anyhow::{Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+/// Advent of Code 2021 solutions.
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// The day to solve
+    day: Option<u8>,
+    /// Only solve this part (1 or 2)
+    part: Option<u8>,
+    /// Read the day's input from this file instead, `-` for stdin
+    #[arg(long, short)]
+    input: Option<PathBuf>,
+    /// Directory holding the `dayNN.txt` inputs [default: $AOC_INPUT_DIR or ./input]
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+    /// Override a puzzle parameter, e.g. `--param days=1000`
+    #[arg(long = "param", short, global = true, value_name = "NAME=VALUE")]
+    params: Vec<String>,
+    /// How to print the answers
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Log what the solvers find along the way to stderr
+    #[arg(long, global = true)]
+    trace: bool,
+    #[command(flatten)]
+    timeout: Timeout,
+}
+
+fn parse_timeout(secs: &str) -> Result<Duration, String> {
+    let secs: f64 = secs.parse().map_err(|err| format!("{}", err))?;
+    Duration::try_from_secs_f64(secs).map_err(|err| format!("{}", err))
+}
+
+#[derive(Args)]
+struct Jobs {
+    /// Number of days to solve at once, 1 to run them one after another for
+    /// more accurate timings [default: number of CPUs]
+    #[arg(long, short)]
+    jobs: Option<usize>,
+}
+
+impl Jobs {
+    fn get(&self) -> usize {
+        self.jobs.unwrap_or_else(runner::default_jobs)
+    }
+}
+
+#[derive(Args)]
+struct Timeout {
+    /// Give up on a day that takes longer than this many seconds
+    #[arg(long = "timeout", value_name = "SECS", value_parser = parse_timeout)]
+    limit: Option<Duration>,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    Text,
+    /// One JSON object per line for each answer
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve every day and print the answers with timings
+    All {
+        #[command(flatten)]
+        jobs: Jobs,
+        #[command(flatten)]
+        timeout: Timeout,
+    },
+    /// Check every day's answers against the recorded ones
+    Verify {
+        #[command(flatten)]
+        jobs: Jobs,
+        #[command(flatten)]
+        timeout: Timeout,
+        /// File holding the recorded answers [default: answers.toml in the crate root]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Download puzzle inputs that are not cached yet, using $AOC_SESSION
+    Fetch {
+        /// The days to download
+        #[arg(required = true)]
+        days: Vec<u8>,
+        /// Site to download from [default: $AOC_BASE_URL or https://adventofcode.com]
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Time parsing and both parts over many runs
+    Bench {
+        /// The days to benchmark [default: all]
+        days: Vec<u8>,
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Timed runs
+        #[arg(long, default_value_t = 20)]
+        iterations: usize,
+        /// Save the results to this baseline file
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare the results with this baseline file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Flag steps whose median got slower than the baseline by more than this percentage
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Create the source, binary and empty input for a new day
+    New {
+        /// The day to create
+        day: u8,
+        /// The puzzle's title [default: Day N]
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// List the parameters each day accepts through `--param`
+    Params,
+    /// Count the allocations made by each day's parse, part 1 and part 2
+    Profile {
+        /// The days to profile [default: all]
+        days: Vec<u8>,
+    },
+    /// Write a Markdown table of every day's stars and timings
+    Report {
+        #[command(flatten)]
+        jobs: Jobs,
+        #[command(flatten)]
+        timeout: Timeout,
+        /// Write the report to this file [default: stdout]
+        output: Option<PathBuf>,
+        /// Also rewrite the report section of the README
+        #[arg(long)]
+        readme: bool,
+        /// File holding the recorded answers [default: answers.toml in the crate root]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Solve inputs sent to `POST /day/{n}/part/{p}` over HTTP
+    Serve {
+        /// Address to listen on, which must be a loopback one
+        #[arg(long, default_value = "127.0.0.1:2021")]
+        addr: SocketAddr,
+        /// Number of requests to handle at once [default: number of CPUs]
+        #[arg(long, short)]
+        jobs: Option<usize>,
+        /// Give up on a request that takes longer than this many seconds
+        #[arg(long, value_name = "SECS", value_parser = parse_timeout, default_value = "10")]
+        timeout: Duration,
+    },
+    /// Send an answer to the site, unless it is already known to be wrong
+    Submit {
+        /// The day to submit
+        day: u8,
+        /// The part to submit (1 or 2)
+        part: u8,
+        /// Submit this instead of solving the day's input
+        #[arg(long)]
+        answer: Option<String>,
+        /// Site to submit to [default: $AOC_BASE_URL or https://adventofcode.com]
+        #[arg(long)]
+        base_url: Option<String>,
+        /// File recording every submission [default: submissions.json in the crate root]
+        #[arg(long)]
+        history: Option<PathBuf>,
+        #[command(flatten)]
+        timeout: Timeout,
+    },
+    /// Re-solve a day whenever its input file changes
+    Watch {
+        /// The day to solve
+        day: u8,
+        /// Watch this file instead of the day's input
+        #[arg(long, short)]
+        input: Option<PathBuf>,
+        /// Also watch this sample input
+        #[arg(long)]
+        sample: Option<PathBuf>,
+        /// How often to check the files, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+        /// Give up on solving an input that takes longer than this many seconds
+        #[arg(long, value_name = "SECS", value_parser = parse_timeout, default_value = "10")]
+        timeout: Duration,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    if cli.trace {
+        tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::TRACE)
+            .with_writer(io::stderr)
+            .with_ansi(io::stderr().is_terminal())
+            .init();
+    }
+    let inputs = cli.input_dir.map_or_else(Inputs::from_env, Inputs::new);
+    let params: Params = cli.params.join(",").parse()?;
+    match cli.command {
+        Some(Command::All { jobs, timeout }) => {
+            solution::check_params(solution::all(), &params)?;
+            let start = Instant::now();
+            let runs = runner::run_all(&inputs, &params, jobs.get(), timeout.limit);
+            match cli.format {
+                Format::Text => runner::print_table(&runs, start.elapsed()),
+                Format::Json => {
+                    for run in &runs {
+                        match run {
+                            Ok(run) => runner::print_json(run, None, &inputs.path(run.day))?,
+                            Err(failure) => eprintln!("{}", failure),
+                        }
+                    }
+                }
+            }
+            runner::ensure_solved(&runs)?;
+        }
+        Some(Command::Verify {
+            jobs,
+            timeout,
+            answers,
+        }) => {
+            if !cli.params.is_empty() {
+                anyhow::bail!("`--param` can't be used with verify, the answers are for the default parameters");
+            }
+            let answers = Answers::load(&answers.unwrap_or_else(Answers::default_path))?;
+            let checks = verify::verify(&answers, &inputs, jobs.get(), timeout.limit);
+            verify::print_checks(&checks);
+            let mismatches = checks.iter().filter(|c| c.is_mismatch()).count();
+            if mismatches > 0 {
+                anyhow::bail!("{} answer(s) did not match", mismatches);
+            }
+            let failures = checks.iter().filter(|c| c.is_failure()).count();
+            if failures > 0 {
+                anyhow::bail!("{} part(s) could not be solved", failures);
+            }
+        }
+        Some(Command::Fetch { days, base_url }) => {
+            let mut client = Client::from_env(base_url.as_deref())?;
+            for day in days {
+                match client.fetch(day, &inputs)? {
+                    Fetched::Cached(path) => {
+                        println!("day {:02}: cached at {}", day, path.display())
+                    }
+                    Fetched::Downloaded(path) => {
+                        println!("day {:02}: saved to {}", day, path.display())
+                    }
+                }
+            }
+        }
+        Some(Command::Bench {
+            days,
+            warmup,
+            iterations,
+            save,
+            baseline,
+            threshold,
+        }) => {
+            let days = if days.is_empty() {
+                solution::all().iter().collect()
+            } else {
+                days.into_iter()
+                    .map(solution::get)
+                    .collect::<Result<Vec<_>>>()?
+            };
+            solution::check_params(days.iter().copied(), &params)?;
+            let mut benches = Vec::new();
+            for day in days {
+                let input = inputs.load(day.day)?;
+                benches.push((
+                    day.day,
+                    bench::bench_day(day, &input, &params, warmup, iterations)?,
+                ));
+            }
+
+            let previous = baseline.map(|path| Baseline::load(&path)).transpose()?;
+            let regressions = bench::print_benches(&benches, previous.as_ref(), threshold);
+            if let Some(path) = save {
+                // keep the days that were not benchmarked this time
+                let mut saved = match path.exists() {
+                    true => Baseline::load(&path)?,
+                    false => Baseline::default(),
+                };
+                for (day, bench) in benches {
+                    saved.insert(day, bench);
+                }
+                saved.save(&path)?;
+            }
+            if regressions > 0 {
+                anyhow::bail!("{} step(s) got slower than the baseline", regressions);
+            }
+        }
+        Some(Command::New { day, title }) => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            let title = title.unwrap_or_else(|| format!("Day {}", day));
+            for path in scaffold::new_day(root, day, &title, &inputs)? {
+                println!("{}", path.display());
+            }
+        }
+        Some(Command::Params) => solution::print_params(),
+        Some(Command::Profile { days }) => {
+            if !profile::enabled() {
+                anyhow::bail!(
+                    "Allocations are only counted when built with `--features alloc-profiling`"
+                );
+            }
+            let days = if days.is_empty() {
+                solution::all().iter().collect()
+            } else {
+                days.into_iter()
+                    .map(solution::get)
+                    .collect::<Result<Vec<_>>>()?
+            };
+            solution::check_params(days.iter().copied(), &params)?;
+            // one day at a time, as the counters are shared by all threads
+            let mut profiles = Vec::new();
+            for day in days {
+                let input = inputs.load(day.day)?;
+                profiles.push((day.day, profile::profile_day(day, &input, &params)?));
+            }
+            profile::print_profiles(&profiles);
+        }
+        Some(Command::Report {
+            jobs,
+            timeout,
+            output,
+            readme,
+            answers,
+        }) => {
+            if !cli.params.is_empty() {
+                anyhow::bail!("`--param` can't be used with report, stars are only earned with the default parameters");
+            }
+            let answers = Answers::load(&answers.unwrap_or_else(Answers::default_path))?;
+            // stars are only earned with the default parameters
+            let runs = runner::run_all(&inputs, &Params::default(), jobs.get(), timeout.limit);
+            let table = report::render(&runs, &verify::check_runs(&answers, &runs));
+            if readme {
+                let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md");
+                report::update_readme(&path, &table)?;
+            }
+            match output {
+                Some(path) => fs::write(&path, &table)
+                    .with_context(|| format!("Could not write `{}`", path.display()))?,
+                None if !readme => print!("{}", table),
+                None => {}
+            }
+            runner::ensure_solved(&runs)?;
+        }
+        Some(Command::Serve {
+            addr,
+            jobs,
+            timeout,
+        }) => serve::serve(addr, jobs.unwrap_or_else(runner::default_jobs), timeout)?,
+        Some(Command::Submit {
+            day,
+            part,
+            answer,
+            base_url,
+            history,
+            timeout,
+        }) => {
+            if !(1..=2).contains(&part) {
+                anyhow::bail!("There is no part {}", part);
+            }
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    let solution = solution::get(day)?;
+                    solution::check_params([solution], &params)?;
+                    let input = inputs.load(day)?;
+                    cancel::with_timeout(timeout.limit, move || {
+                        let parsed = solution.solver.parse(&input, &params)?;
+                        Ok(solution.solver.part(&parsed, part, &params)?.to_string())
+                    })?
+                }
+            };
+            let path = history.unwrap_or_else(History::default_path);
+            let mut history = History::load(&path)?;
+            let mut client = Client::from_env(base_url.as_deref())?;
+            let verdict = submit::submit(&mut client, &mut history, day, part, &answer);
+            history.save(&path)?;
+            let verdict = verdict?;
+            println!("day {:02} part {}: `{}` is {}", day, part, answer, verdict);
+        }
+        Some(Command::Watch {
+            day,
+            input,
+            sample,
+            interval,
+            timeout,
+        }) => {
+            let day = solution::get(day)?;
+            solution::check_params([day], &params)?;
+            let mut files = vec![input.unwrap_or_else(|| inputs.path(day.day))];
+            files.extend(sample);
+            watch::watch(
+                day,
+                files,
+                &params,
+                Duration::from_millis(interval),
+                timeout,
+            )
+        }
+        None => {
+            let day = cli.day.context("Missing day to solve")?;
+            solution::check_params([solution::get(day)?], &params)?;
+            let path = cli.input.unwrap_or_else(|| inputs.path(day));
+            let input = input::read(day, &path)?;
+            match cli.format {
+                Format::Text => cancel::with_timeout(cli.timeout.limit, move || {
+                    runner::run_day(day, cli.part, &input, &params)
+                })?,
+                Format::Json => cancel::with_timeout(cli.timeout.limit, move || {
+                    runner::run_day_json(day, cli.part, &input, &params, &path)
+                })?,
+            }
+        }
+    }
+    Ok(())
 }
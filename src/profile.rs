@@ -0,0 +1,168 @@
+use anyhow::Result;
+
+use crate::solution::{Day, Params};
+
+/// What a piece of code allocated while it ran.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// The most bytes live at once, on top of what was live before.
+    pub peak_bytes: u64,
+}
+
+/// Whether allocations are being counted, i.e. the crate was built with the
+/// `alloc-profiling` feature.
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-profiling")
+}
+
+#[cfg(feature = "alloc-profiling")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    pub static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+    pub static BYTES: AtomicU64 = AtomicU64::new(0);
+    pub static LIVE: AtomicU64 = AtomicU64::new(0);
+    pub static PEAK: AtomicU64 = AtomicU64::new(0);
+
+    /// Hands everything to the system allocator, counting as it goes.
+    pub struct CountingAllocator;
+
+    impl CountingAllocator {
+        fn record(&self, grown_by: u64) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            BYTES.fetch_add(grown_by, Ordering::Relaxed);
+            let live = LIVE.fetch_add(grown_by, Ordering::Relaxed) + grown_by;
+            PEAK.fetch_max(live, Ordering::Relaxed);
+        }
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                self.record(layout.size() as u64);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            LIVE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                let (old, new) = (layout.size() as u64, new_size as u64);
+                if new >= old {
+                    self.record(new - old);
+                } else {
+                    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+                    LIVE.fetch_sub(old - new, Ordering::Relaxed);
+                }
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+}
+
+/// Runs `f` and reports what it allocated. The counters are global, so other
+/// threads allocating at the same time end up in the numbers too. Always
+/// reports nothing unless [`enabled`].
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    #[cfg(feature = "alloc-profiling")]
+    {
+        use counting::{ALLOCATIONS, BYTES, LIVE, PEAK};
+        use std::sync::atomic::Ordering;
+
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        let bytes = BYTES.load(Ordering::Relaxed);
+        let live = LIVE.load(Ordering::Relaxed);
+        PEAK.store(live, Ordering::Relaxed);
+
+        let result = f();
+
+        let stats = AllocStats {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+            bytes: BYTES.load(Ordering::Relaxed) - bytes,
+            peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+        };
+        (result, stats)
+    }
+    #[cfg(not(feature = "alloc-profiling"))]
+    (f(), AllocStats::default())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DayProfile {
+    pub parse: AllocStats,
+    pub part1: AllocStats,
+    pub part2: AllocStats,
+}
+
+impl DayProfile {
+    pub fn steps(&self) -> [(&'static str, &AllocStats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+/// Parses and solves `day` once, recording the allocations of every step.
+pub fn profile_day(day: &Day, input: &str, params: &Params) -> Result<DayProfile> {
+    let (parsed, parse) = measure(|| day.solver.parse(input, params));
+    let parsed = parsed?;
+    let (answer, part1) = measure(|| day.solver.part1(&parsed, params));
+    answer?;
+    let (answer, part2) = measure(|| day.solver.part2(&parsed, params));
+    answer?;
+    Ok(DayProfile {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+pub fn print_profiles(profiles: &[(u8, DayProfile)]) {
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>14}  {:>14}",
+        "Day", "Step", "Allocations", "Bytes", "Peak bytes"
+    );
+    for (day, profile) in profiles {
+        for (step, stats) in profile.steps() {
+            println!(
+                "{:>3}  {:<5}  {:>12}  {:>14}  {:>14}",
+                day, step, stats.allocations, stats.bytes, stats.peak_bytes
+            );
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc-profiling"))]
+mod profile_tests {
+    use super::measure;
+
+    #[test]
+    fn test_measure() {
+        let (_, stats) = measure(|| {
+            let a: Vec<u64> = Vec::with_capacity(128);
+            let b: Vec<u8> = Vec::with_capacity(1000);
+            drop(a);
+            drop(b);
+        });
+        // the test harness may allocate on other threads meanwhile
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 2024);
+        assert!(stats.peak_bytes >= 2024);
+    }
+}
@@ -0,0 +1,150 @@
+use std::{fmt::Write, fs, path::Path, time::Duration};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    runner::{DayFailure, DayRun},
+    solution,
+    verify::Check,
+};
+
+/// The report replaces everything between these two lines of a README.
+pub const START_MARKER: &str = "<!-- report start -->";
+pub const END_MARKER: &str = "<!-- report end -->";
+
+/// A Markdown table of every run with the puzzle, the stars earned by the
+/// parts `checks` found correct, how long each part took, or that it failed,
+/// and a link to the code. Links are relative to the crate root.
+pub fn render(runs: &[Result<DayRun, DayFailure>], checks: &[Check]) -> String {
+    let mut table = String::new();
+    table.push_str("| Day | Puzzle | Stars | Part 1 | Part 2 | Source |\n");
+    table.push_str("|----:|--------|:-----:|-------:|-------:|--------|\n");
+    for run in runs {
+        let (day, part1, part2) = match run {
+            Ok(run) => (
+                run.day,
+                format!("{:.2?}", run.part1_time),
+                format!("{:.2?}", run.part2_time),
+            ),
+            Err(failure) => (failure.day, "failed".to_string(), "failed".to_string()),
+        };
+        let title = solution::get(day).map_or("", |day| day.title);
+        let stars = checks
+            .iter()
+            .filter(|check| check.day == day && check.is_correct())
+            .count();
+        let source = format!("src/day{:02}.rs", day);
+        writeln!(
+            table,
+            "| {} | [{}](https://adventofcode.com/2021/day/{}) | {} | {} | {} | [{}]({}) |",
+            day,
+            title,
+            day,
+            if stars == 0 {
+                "-".to_string()
+            } else {
+                "⭐".repeat(stars)
+            },
+            part1,
+            part2,
+            source,
+            source
+        )
+        .unwrap();
+    }
+
+    let stars = checks.iter().filter(|check| check.is_correct()).count();
+    let total: Duration = runs.iter().flatten().map(DayRun::total_time).sum();
+    writeln!(
+        table,
+        "\n{} of {} stars, solved in {:.2?} including parsing.",
+        stars,
+        runs.len() * 2,
+        total
+    )
+    .unwrap();
+    table
+}
+
+/// `content` with the lines between the report markers replaced by `report`.
+pub fn replace_section(content: &str, report: &str) -> Result<String> {
+    let start = content
+        .find(START_MARKER)
+        .with_context(|| format!("Missing `{}`", START_MARKER))?
+        + START_MARKER.len();
+    let end = start
+        + content[start..]
+            .find(END_MARKER)
+            .with_context(|| format!("Missing `{}` after `{}`", END_MARKER, START_MARKER))?;
+    Ok(format!(
+        "{}\n{}{}",
+        &content[..start],
+        report,
+        &content[end..]
+    ))
+}
+
+/// Rewrites the report section of the README at `path`.
+pub fn update_readme(path: &Path, report: &str) -> Result<()> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Could not read `{}`", path.display()))?;
+    let content = replace_section(&content, report)
+        .with_context(|| format!("Could not find the report section in `{}`", path.display()))?;
+    fs::write(path, content).with_context(|| format!("Could not write `{}`", path.display()))
+}
+
+#[cfg(test)]
+mod report_tests {
+    use std::time::Duration;
+
+    use super::{render, replace_section};
+    use crate::{
+        runner::{DayFailure, DayRun},
+        verify::Check,
+    };
+
+    #[test]
+    fn test_render() {
+        let run = DayRun {
+            day: 6,
+            part1: 5934u64.into(),
+            part2: 26984457539u64.into(),
+            parse_time: Duration::from_micros(3),
+            part1_time: Duration::from_micros(5),
+            part2_time: Duration::from_micros(12),
+        };
+        let checks = [
+            Check::new(6, 1, Some("5934".to_string()), "5934".to_string()),
+            Check::new(6, 2, None, "26984457539".to_string()),
+        ];
+        let failure = DayFailure {
+            day: 11,
+            error: anyhow::anyhow!("timed out after 1s"),
+        };
+        let report = render(&[Ok(run), Err(failure)], &checks);
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(
+            lines[2],
+            "| 6 | [Lanternfish](https://adventofcode.com/2021/day/6) | ⭐ | 5.00µs | 12.00µs | [src/day06.rs](src/day06.rs) |"
+        );
+        assert_eq!(
+            lines[3],
+            "| 11 | [Dumbo Octopus](https://adventofcode.com/2021/day/11) | - | failed | failed | [src/day11.rs](src/day11.rs) |"
+        );
+        assert_eq!(
+            lines[5],
+            "1 of 4 stars, solved in 20.00µs including parsing."
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# Title\n<!-- report start -->\nold\n<!-- report end -->\nmore\n";
+        assert_eq!(
+            replace_section(readme, "new\n").unwrap(),
+            "# Title\n<!-- report start -->\nnew\n<!-- report end -->\nmore\n"
+        );
+        assert!(replace_section("# Title\n", "new\n").is_err());
+        assert!(replace_section("<!-- report end -->\n<!-- report start -->\n", "new\n").is_err());
+    }
+}
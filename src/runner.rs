@@ -0,0 +1,368 @@
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use serde::Serialize;
+use tracing::Span;
+
+use crate::{
+    cancel,
+    input::Inputs,
+    solution::{self, Answer, Day, Params},
+};
+
+/// The answers for one day along with how long each step took.
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    pub part1: Answer,
+    pub part2: Answer,
+    pub parse_time: Duration,
+    pub part1_time: Duration,
+    pub part2_time: Duration,
+}
+
+impl DayRun {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.part1_time + self.part2_time
+    }
+
+    /// The answer to `part` and how long it took.
+    pub fn part(&self, part: u8) -> Option<(Answer, Duration)> {
+        match part {
+            1 => Some((self.part1, self.part1_time)),
+            2 => Some((self.part2, self.part2_time)),
+            _ => None,
+        }
+    }
+}
+
+/// A day [`run_all`] could not solve, like one that ran out of time.
+#[derive(Debug)]
+pub struct DayFailure {
+    pub day: u8,
+    pub error: anyhow::Error,
+}
+
+impl fmt::Display for DayFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {} failed - {:#}", self.day, self.error)
+    }
+}
+
+/// One line of the `--format json` output.
+#[derive(Serialize)]
+struct JsonResult<'a> {
+    day: u8,
+    part: u8,
+    answer: Answer,
+    #[serde(rename = "type")]
+    answer_type: &'static str,
+    elapsed_ns: u64,
+    input: &'a Path,
+}
+
+/// Parses `input` and solves both parts, timing each step separately.
+pub fn run(day: &Day, input: &str, params: &Params) -> Result<DayRun> {
+    let _span = tracing::info_span!("day", day = day.day).entered();
+    let (parsed, parse_time) = timed(tracing::info_span!("parse"), || {
+        day.solver.parse(input, params)
+    })?;
+    let (part1, part1_time) = timed(tracing::info_span!("part", part = 1), || {
+        day.solver.part1(&parsed, params)
+    })?;
+    let (part2, part2_time) = timed(tracing::info_span!("part", part = 2), || {
+        day.solver.part2(&parsed, params)
+    })?;
+    Ok(DayRun {
+        day: day.day,
+        part1,
+        part2,
+        parse_time,
+        part1_time,
+        part2_time,
+    })
+}
+
+/// Runs `f` inside `span`, timing it.
+fn timed<T>(span: Span, f: impl FnOnce() -> Result<T>) -> Result<(T, Duration)> {
+    let _span = span.entered();
+    let start = Instant::now();
+    let result = f()?;
+    let elapsed = start.elapsed();
+    tracing::debug!(?elapsed, "finished");
+    Ok((result, elapsed))
+}
+
+/// Solves `day` against `input` and prints the answer to `part`, or to both
+/// parts when `part` is `None`.
+pub fn run_day(day: u8, part: Option<u8>, input: &str, params: &Params) -> Result<()> {
+    solve_parts(day, part, input, params, |_, answer, _| {
+        println!("{}", answer);
+        Ok(())
+    })
+}
+
+/// Like [`run_day`], printing the answers as one JSON object per line along
+/// with the `path` the input came from.
+pub fn run_day_json(
+    day: u8,
+    part: Option<u8>,
+    input: &str,
+    params: &Params,
+    path: &Path,
+) -> Result<()> {
+    solve_parts(day, part, input, params, |part, answer, elapsed| {
+        print_json_line(day, part, answer, elapsed, path)
+    })
+}
+
+/// Solves only `part`, or both parts, passing each answer to `f` with how
+/// long it took. An unknown part fails before anything is solved.
+fn solve_parts(
+    day: u8,
+    part: Option<u8>,
+    input: &str,
+    params: &Params,
+    mut f: impl FnMut(u8, Answer, Duration) -> Result<()>,
+) -> Result<()> {
+    let parts = match part {
+        None => vec![1, 2],
+        Some(part @ (1 | 2)) => vec![part],
+        Some(part) => anyhow::bail!("There is no part {}", part),
+    };
+    let _span = tracing::info_span!("day", day).entered();
+    let solver = solution::get(day)?.solver;
+    let parsed = tracing::info_span!("parse").in_scope(|| solver.parse(input, params))?;
+    for part in parts {
+        let (answer, elapsed) = timed(tracing::info_span!("part", part), || {
+            solver.part(&parsed, part, params)
+        })?;
+        f(part, answer, elapsed)?;
+    }
+    Ok(())
+}
+
+/// Runs every registered day against its input in `inputs`, spreading the days
+/// over `jobs` threads and giving each one up to `timeout`. A day that fails or
+/// panics doesn't stop the others, and the runs come back in day order either
+/// way.
+pub fn run_all(
+    inputs: &Inputs,
+    params: &Params,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<Result<DayRun, DayFailure>> {
+    run_days(solution::all(), inputs, params, jobs, timeout)
+}
+
+fn run_days(
+    days: &'static [Day],
+    inputs: &Inputs,
+    params: &Params,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<Result<DayRun, DayFailure>> {
+    parallel_map(days, jobs, |day| {
+        inputs
+            .load(day.day)
+            .and_then(|input| {
+                let params = params.clone();
+                cancel::with_timeout(timeout, move || {
+                    // the solvers hold no state a panic could leave broken
+                    panic::catch_unwind(AssertUnwindSafe(|| run(day, &input, &params)))
+                        .unwrap_or_else(|_| Err(anyhow::anyhow!("The solver panicked")))
+                })
+            })
+            .map_err(|error| DayFailure {
+                day: day.day,
+                error,
+            })
+    })
+}
+
+/// Fails if any of `runs` did, once they have all been reported.
+pub fn ensure_solved(runs: &[Result<DayRun, DayFailure>]) -> Result<()> {
+    let failed = runs.iter().filter(|run| run.is_err()).count();
+    if failed > 0 {
+        anyhow::bail!("{} day(s) failed", failed);
+    }
+    Ok(())
+}
+
+/// Applies `f` to every item on up to `jobs` threads and returns the results in
+/// the order of `items`. With one job everything runs on the calling thread,
+/// which keeps timings free of interference from the other days.
+pub fn parallel_map<'a, T, R, F>(items: &'a [T], jobs: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&'a T) -> R + Sync,
+{
+    if jobs <= 1 || items.len() <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(items.len()));
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result = f(item);
+                results.lock().unwrap().push((index, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// The number of threads to use when none is given.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Prints the answers and timings of every run, or why it failed, then the sum
+/// of the timings and the `wall_time` the whole run took.
+pub fn print_table(runs: &[Result<DayRun, DayFailure>], wall_time: Duration) {
+    println!(
+        "{:>3}  {:>16}  {:>16}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Part 1", "Part 2", "Parse", "Part 1", "Part 2", "Total"
+    );
+    for run in runs {
+        let run = match run {
+            Ok(run) => run,
+            Err(failure) => {
+                println!("{:>3}  failed - {:#}", failure.day, failure.error);
+                continue;
+            }
+        };
+        println!(
+            "{:>3}  {:>16}  {:>16}  {:>10}  {:>10}  {:>10}  {:>10}",
+            run.day,
+            run.part1.to_string(),
+            run.part2.to_string(),
+            format!("{:.2?}", run.parse_time),
+            format!("{:.2?}", run.part1_time),
+            format!("{:.2?}", run.part2_time),
+            format!("{:.2?}", run.total_time()),
+        );
+    }
+    let total: Duration = runs.iter().flatten().map(DayRun::total_time).sum();
+    println!("{:<5}{:>82}", "Total", format!("{:.2?}", total));
+    println!("{:<9}{:>78}", "Wall time", format!("{:.2?}", wall_time));
+}
+
+/// Prints the answer to `part`, or to both parts, as one JSON object per line.
+pub fn print_json(run: &DayRun, part: Option<u8>, input: &Path) -> Result<()> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    for part in parts {
+        let (answer, elapsed) = run
+            .part(part)
+            .ok_or(anyhow::anyhow!("Invalid part `{}`", part))?;
+        print_json_line(run.day, part, answer, elapsed, input)?;
+    }
+    Ok(())
+}
+
+fn print_json_line(
+    day: u8,
+    part: u8,
+    answer: Answer,
+    elapsed: Duration,
+    input: &Path,
+) -> Result<()> {
+    let result = JsonResult {
+        day,
+        part,
+        answer,
+        answer_type: answer.type_name(),
+        elapsed_ns: elapsed.as_nanos() as u64,
+        input,
+    };
+    println!("{}", serde_json::to_string(&result)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod runner_tests {
+    use std::{env, fs};
+
+    use anyhow::Result;
+
+    use super::run_days;
+    use crate::{
+        day06::Day06,
+        input::Inputs,
+        solution::{Day, Params, Solution},
+    };
+
+    struct Panics;
+
+    impl Solution for Panics {
+        type Input = ();
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(&self, _input: &str, _params: &Params) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1(&self, _input: &(), _params: &Params) -> Result<u32> {
+            panic!("part 1 panicked")
+        }
+
+        fn part2(&self, _input: &(), _params: &Params) -> Result<u32> {
+            Ok(0)
+        }
+    }
+
+    static DAYS: &[Day] = &[
+        Day {
+            day: 6,
+            title: "Lanternfish",
+            solver: &Day06,
+        },
+        Day {
+            day: 7,
+            title: "Panics",
+            solver: &Panics,
+        },
+    ];
+
+    #[test]
+    fn test_run_days_with_panic() {
+        let dir = env::temp_dir().join(format!("aoc-runner-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day06.txt"), "3,4,3,1,2").unwrap();
+        fs::write(dir.join("day07.txt"), "").unwrap();
+        let inputs = Inputs::new(&dir);
+
+        for jobs in [1, 2] {
+            let runs = run_days(DAYS, &inputs, &Params::default(), jobs, None);
+            assert_eq!(runs[0].as_ref().unwrap().part1, 5934u64.into());
+            let failure = runs[1].as_ref().unwrap_err();
+            assert_eq!(failure.day, 7);
+            assert_eq!(failure.error.to_string(), "The solver panicked");
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use regex::Regex;
+
+use crate::input::Inputs;
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+const BIN_TEMPLATE: &str = include_str!("../templates/bin.rs");
+
+/// Creates the module, binary and empty input for `day` inside the crate at
+/// `root`, and registers the module in `lib.rs` and the list of solutions
+/// under `title`. Returns the files that were created or changed.
+pub fn new_day(root: &Path, day: u8, title: &str, inputs: &Inputs) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        anyhow::bail!("There is no puzzle for day {}", day);
+    }
+    let module = root.join(format!("src/day{:02}.rs", day));
+    if module.exists() {
+        anyhow::bail!("`{}` already exists", module.display());
+    }
+    let fill = |template: &str| {
+        template
+            .replace("{{NN}}", &format!("{:02}", day))
+            .replace("{{N}}", &day.to_string())
+            .replace("{{TITLE}}", title)
+    };
+    // work out both registrations before writing anything, so a failure
+    // leaves the crate as it was
+    let lib = root.join("src/lib.rs");
+    let lib_content = register(
+        &lib,
+        r"^pub mod day(\d+);$",
+        day,
+        &format!("pub mod day{:02};", day),
+    )?;
+    let solutions = root.join("src/solution.rs");
+    let solutions_content = register(
+        &solutions,
+        r"^\s*Day::new\((\d+),",
+        day,
+        &format!(
+            "    Day::new({}, {:?}, &crate::day{:02}::Day{:02}),",
+            day, title, day, day
+        ),
+    )?;
+
+    let bin = root.join(format!("src/bin/day{:02}.rs", day));
+    write(&module, &fill(DAY_TEMPLATE))?;
+    write(&bin, &fill(BIN_TEMPLATE))?;
+    write(&lib, &lib_content)?;
+    write(&solutions, &solutions_content)?;
+    let mut changed = vec![module, bin, lib, solutions];
+
+    let input = inputs.path(day);
+    if !input.exists() {
+        fs::create_dir_all(inputs.dir())?;
+        write(&input, "")?;
+        changed.push(input);
+    }
+    Ok(changed)
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content).with_context(|| format!("Could not write `{}`", path.display()))
+}
+
+/// The content of `path` with `line` added among the lines matching `pattern`.
+fn register(path: &Path, pattern: &str, day: u8, line: &str) -> Result<String> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Could not read `{}`", path.display()))?;
+    insert_sorted(&content, pattern, day, line)
+        .with_context(|| format!("Could not register day {} in `{}`", day, path.display()))
+}
+
+/// Inserts `line` among the lines matching `pattern`, keeping them ordered by
+/// the day number the pattern captures.
+fn insert_sorted(content: &str, pattern: &str, day: u8, line: &str) -> Result<String> {
+    let pattern = Regex::new(pattern)?;
+    let mut lines: Vec<&str> = content.lines().collect();
+
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, pattern.captures(l)?[1].parse().ok()?)))
+        .collect();
+    if days.iter().any(|(_, d)| *d == day) {
+        anyhow::bail!("Day {} is already registered", day);
+    }
+    let index = match days.iter().find(|(_, d)| *d > day) {
+        Some((i, _)) => *i,
+        None => days.last().ok_or(anyhow::anyhow!("No other days found"))?.0 + 1,
+    };
+
+    lines.insert(index, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod scaffold_tests {
+    use super::insert_sorted;
+
+    static LIB: &str = "pub mod day01;\npub mod day03;\npub mod solution;\n";
+
+    #[test]
+    fn test_insert_sorted() {
+        let pattern = r"^pub mod day(\d+);$";
+        assert_eq!(
+            insert_sorted(LIB, pattern, 2, "pub mod day02;").unwrap(),
+            "pub mod day01;\npub mod day02;\npub mod day03;\npub mod solution;\n"
+        );
+        assert_eq!(
+            insert_sorted(LIB, pattern, 12, "pub mod day12;").unwrap(),
+            "pub mod day01;\npub mod day03;\npub mod day12;\npub mod solution;\n"
+        );
+        assert!(insert_sorted(LIB, pattern, 3, "pub mod day03;").is_err());
+        assert!(insert_sorted("", pattern, 3, "pub mod day03;").is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    io::Read,
+    net::SocketAddr,
+    panic::{self, AssertUnwindSafe},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::{
+    cancel::{self, TimedOut},
+    solution::{self, Answer, Params},
+};
+
+/// Bodies larger than this are refused, the real inputs being a few KiB.
+const MAX_INPUT_LEN: u64 = 1 << 20;
+
+/// The body of a successful `POST /day/{n}/part/{p}`.
+#[derive(Serialize)]
+struct Solved {
+    day: u8,
+    part: u8,
+    answer: Answer,
+    #[serde(rename = "type")]
+    answer_type: &'static str,
+    elapsed_ns: u64,
+}
+
+#[derive(Serialize)]
+struct Failed {
+    error: String,
+}
+
+/// Answers `POST /day/{n}/part/{p}` requests on `addr`, which must be a
+/// loopback address, handling up to `jobs` of them at once and giving up on
+/// any that takes longer than `timeout`. Never returns unless the server
+/// can't be started.
+pub fn serve(addr: SocketAddr, jobs: usize, timeout: Duration) -> Result<()> {
+    if !addr.ip().is_loopback() {
+        anyhow::bail!(
+            "Refusing to listen on `{}`, only loopback addresses are allowed",
+            addr
+        );
+    }
+    let server = Server::http(addr)
+        .map_err(|err| anyhow::anyhow!("Could not listen on `{}` - {}", addr, err))?;
+    println!("Listening on http://{}", addr);
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| {
+                for request in server.incoming_requests() {
+                    respond(request, timeout);
+                }
+            });
+        }
+    });
+    Ok(())
+}
+
+fn respond(mut request: Request, timeout: Duration) {
+    let mut body = Vec::new();
+    let read = request
+        .as_reader()
+        .take(MAX_INPUT_LEN + 1)
+        .read_to_end(&mut body);
+    let (status, json) = match read {
+        Err(err) => error(400, format!("Could not read the request body - {}", err)),
+        Ok(_) if body.len() as u64 > MAX_INPUT_LEN => error(
+            413,
+            format!("Inputs are limited to {} bytes", MAX_INPUT_LEN),
+        ),
+        Ok(_) => handle(request.method(), request.url(), &body, timeout),
+    };
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(json)
+        .with_status_code(status)
+        .with_header(content_type);
+    if let Err(err) = request.respond(response) {
+        eprintln!("Could not send a response - {}", err);
+    }
+}
+
+/// The status code and JSON body answering a request for `url`. A solver
+/// still running after `timeout` is answered with 503, and keeps running in
+/// the background if it never checks for cancellation.
+fn handle(method: &Method, url: &str, body: &[u8], timeout: Duration) -> (u16, String) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let (day, part) = match segments[..] {
+        ["day", day, "part", part] => (day, part),
+        _ => return error(404, format!("No route for `{}`", path)),
+    };
+    if *method != Method::Post {
+        return error(405, "Send the input with POST".to_string());
+    }
+    let Some(day) = day.parse().ok().and_then(|day| solution::get(day).ok()) else {
+        return error(404, format!("There is no solution for day `{}`", day));
+    };
+    let part = match part.parse() {
+        Ok(part @ (1 | 2)) => part,
+        _ => return error(404, format!("There is no part `{}`", part)),
+    };
+    // query parameters override the defaults like `--param`
+    let params = match query
+        .replace('&', ",")
+        .parse::<Params>()
+        .and_then(|params| solution::check_params([day], &params).map(|_| params))
+    {
+        Ok(params) => params,
+        Err(err) => return error(400, format!("{:#}", err)),
+    };
+    let Ok(input) = String::from_utf8(body.to_vec()) else {
+        return error(400, "The input is not UTF-8".to_string());
+    };
+
+    let start = Instant::now();
+    let solved = cancel::with_timeout(Some(timeout), move || {
+        // the solvers hold no state a panic could leave broken
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            let parsed = day
+                .solver
+                .parse(&input, &params)
+                .map_err(|err| (400, err))?;
+            day.solver
+                .part(&parsed, part, &params)
+                .map_err(|err| (422, err))
+        }))
+        .map_err(|_| anyhow::anyhow!("The solver panicked"))
+    });
+    let elapsed = start.elapsed();
+    match solved {
+        Ok(Ok(answer)) => json(
+            200,
+            &Solved {
+                day: day.day,
+                part,
+                answer,
+                answer_type: answer.type_name(),
+                elapsed_ns: elapsed.as_nanos() as u64,
+            },
+        ),
+        Ok(Err((status, err))) => error(status, format!("{:#}", err)),
+        Err(err) if err.is::<TimedOut>() => error(503, format!("The solver {}", err)),
+        Err(err) => error(500, format!("{:#}", err)),
+    }
+}
+
+fn error(status: u16, error: String) -> (u16, String) {
+    json(status, &Failed { error })
+}
+
+fn json(status: u16, body: &impl Serialize) -> (u16, String) {
+    let body = serde_json::to_string(body).expect("Responses always serialize");
+    (status, body)
+}
+
+#[cfg(test)]
+mod serve_tests {
+    use std::time::Duration;
+
+    use tiny_http::Method;
+
+    use super::handle;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn post(url: &str, body: &str) -> (u16, String) {
+        handle(&Method::Post, url, body.as_bytes(), TIMEOUT)
+    }
+
+    #[test]
+    fn test_handle() {
+        let (status, body) = post("/day/6/part/1", "3,4,3,1,2");
+        assert_eq!(status, 200);
+        assert!(body.starts_with(r#"{"day":6,"part":1,"answer":5934,"type":"u64","#));
+        let (status, body) = post("/day/6/part/2?days=18", "3,4,3,1,2\n");
+        assert_eq!(status, 200);
+        assert!(body.contains(r#""answer":26,"#));
+
+        assert_eq!(post("/day/6/part/1", "3,x").0, 400);
+        assert_eq!(post("/day/2/part/1", "hello world").0, 400);
+        assert!(post("/day/7/part/2", "5").1.contains(r#""answer":0,"#));
+        assert_eq!(post("/day/5/part/2", "0,0 -> 2,5").0, 400);
+        assert_eq!(post("/day/10/part/1", "abc").0, 400);
+        assert_eq!(post("/day/3/part/1", "").0, 400);
+        assert_eq!(post("/day/6/part/1", "9,1").0, 400);
+        assert_eq!(post("/day/4/part/1?board_size=0", "1,2\n\n1").0, 400);
+        assert_eq!(post("/day/6/part/1?weeks=2", "3,4").0, 400);
+        assert_eq!(post("/day/4/part/1", "1,2").0, 422);
+        assert_eq!(post("/day/30/part/1", "").0, 404);
+        assert_eq!(post("/day/6/part/3", "").0, 404);
+        assert_eq!(post("/days", "").0, 404);
+        assert_eq!(handle(&Method::Get, "/day/6/part/1", b"", TIMEOUT).0, 405);
+
+        let (status, body) = handle(
+            &Method::Post,
+            "/day/11/part/1?steps=4000000000",
+            b"5483143223\n2745854711",
+            Duration::from_millis(50),
+        );
+        assert_eq!(status, 503);
+        assert_eq!(body, r#"{"error":"The solver timed out after 0.05s"}"#);
+    }
+}
@@ -0,0 +1,356 @@
+use std::{any::Any, collections::BTreeMap, fmt, str::FromStr};
+
+use anyhow::Result;
+use serde::{Serialize, Serializer};
+
+/// The answer to one part of a puzzle, keeping the type the solver produced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    I32(i32),
+    U32(u32),
+    U64(u64),
+    Usize(usize),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::I32(v) => write!(f, "{}", v),
+            Answer::U32(v) => write!(f, "{}", v),
+            Answer::U64(v) => write!(f, "{}", v),
+            Answer::Usize(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl Answer {
+    /// The name of the Rust type the solver returned.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::I32(_) => "i32",
+            Answer::U32(_) => "u32",
+            Answer::U64(_) => "u64",
+            Answer::Usize(_) => "usize",
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Answer::I32(v) => serializer.serialize_i32(v),
+            Answer::U32(v) => serializer.serialize_u32(v),
+            Answer::U64(v) => serializer.serialize_u64(v),
+            Answer::Usize(v) => serializer.serialize_u64(v as u64),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(v: i32) -> Self {
+        Answer::I32(v)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(v: u32) -> Self {
+        Answer::U32(v)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(v: u64) -> Self {
+        Answer::U64(v)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(v: usize) -> Self {
+        Answer::Usize(v)
+    }
+}
+
+/// A named value a solution reads instead of hardcoding it.
+pub struct Param {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// The values used by part 1 and part 2. Parsing sees part 1's.
+    pub defaults: [&'static str; 2],
+}
+
+impl Param {
+    pub const fn new(
+        name: &'static str,
+        default: &'static str,
+        description: &'static str,
+    ) -> Param {
+        Param::per_part(name, default, default, description)
+    }
+
+    pub const fn per_part(
+        name: &'static str,
+        part1: &'static str,
+        part2: &'static str,
+        description: &'static str,
+    ) -> Param {
+        Param {
+            name,
+            description,
+            defaults: [part1, part2],
+        }
+    }
+}
+
+/// Parameter values by name, either as given on the command line or resolved
+/// against a solution's [`Param`]s.
+#[derive(Debug, Clone, Default)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.0.insert(name.to_string(), value.to_string());
+    }
+
+    /// The defaults of `params` for `part`, replaced by any of the `overrides`.
+    pub fn resolve(params: &[Param], overrides: &Params, part: u8) -> Params {
+        let index = if part == 2 { 1 } else { 0 };
+        Params(
+            params
+                .iter()
+                .map(|param| {
+                    let value = overrides
+                        .0
+                        .get(param.name)
+                        .map_or(param.defaults[index], String::as_str);
+                    (param.name.to_string(), value.to_string())
+                })
+                .collect(),
+        )
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    pub fn get<T>(&self, name: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let value = self
+            .0
+            .get(name)
+            .ok_or(anyhow::anyhow!("Missing parameter `{}`", name))?;
+        value
+            .parse()
+            .map_err(|err| anyhow::anyhow!("Invalid value `{}` for `{}` - {}", value, name, err))
+    }
+}
+
+impl FromStr for Params {
+    type Err = anyhow::Error;
+
+    /// Parses comma separated `name=value` pairs.
+    fn from_str(s: &str) -> Result<Params> {
+        let mut params = Params::default();
+        for pair in s.split(',').filter(|p| !p.is_empty()) {
+            let (name, value) = pair
+                .split_once('=')
+                .ok_or(anyhow::anyhow!("Expected `name=value`, got `{}`", pair))?;
+            params.set(name.trim(), value.trim());
+        }
+        Ok(params)
+    }
+}
+
+/// A single day's puzzle: parse the input once, then solve both parts from it.
+pub trait Solution {
+    type Input;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    /// The parameters the parts read from their `params`.
+    fn params(&self) -> &'static [Param] {
+        &[]
+    }
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input, params: &Params) -> Result<Self::Part1>;
+    fn part2(&self, input: &Self::Input, params: &Params) -> Result<Self::Part2>;
+}
+
+/// Parsed input handed between [`Solver::parse`] and the part functions.
+pub type Parsed = Box<dyn Any>;
+
+/// Object safe view of a [`Solution`], used by the registry. Takes the
+/// parameters given on the command line and fills in the defaults.
+pub trait Solver {
+    fn params(&self) -> &'static [Param];
+    fn parse(&self, input: &str, overrides: &Params) -> Result<Parsed>;
+    fn part1(&self, input: &Parsed, overrides: &Params) -> Result<Answer>;
+    fn part2(&self, input: &Parsed, overrides: &Params) -> Result<Answer>;
+
+    fn part(&self, input: &Parsed, part: u8, overrides: &Params) -> Result<Answer> {
+        match part {
+            1 => self.part1(input, overrides),
+            2 => self.part2(input, overrides),
+            _ => Err(anyhow::anyhow!("Invalid part `{}`", part)),
+        }
+    }
+}
+
+impl<S> Solver for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn params(&self) -> &'static [Param] {
+        Solution::params(self)
+    }
+
+    fn parse(&self, input: &str, overrides: &Params) -> Result<Parsed> {
+        let params = Params::resolve(Solution::params(self), overrides, 1);
+        Ok(Box::new(Solution::parse(self, input, &params)?))
+    }
+
+    fn part1(&self, input: &Parsed, overrides: &Params) -> Result<Answer> {
+        let params = Params::resolve(Solution::params(self), overrides, 1);
+        Ok(Solution::part1(self, downcast::<S>(input)?, &params)?.into())
+    }
+
+    fn part2(&self, input: &Parsed, overrides: &Params) -> Result<Answer> {
+        let params = Params::resolve(Solution::params(self), overrides, 2);
+        Ok(Solution::part2(self, downcast::<S>(input)?, &params)?.into())
+    }
+}
+
+fn downcast<S>(input: &Parsed) -> Result<&S::Input>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .ok_or(anyhow::anyhow!("Input was parsed by a different solver"))
+}
+
+pub struct Day {
+    pub day: u8,
+    /// The puzzle's title on the site.
+    pub title: &'static str,
+    pub solver: &'static (dyn Solver + Sync),
+}
+
+impl Day {
+    const fn new(day: u8, title: &'static str, solver: &'static (dyn Solver + Sync)) -> Day {
+        Day { day, title, solver }
+    }
+}
+
+static DAYS: &[Day] = &[
+    Day::new(1, "Sonar Sweep", &crate::day01::Day01),
+    Day::new(2, "Dive!", &crate::day02::Day02),
+    Day::new(3, "Binary Diagnostic", &crate::day03::Day03),
+    Day::new(4, "Giant Squid", &crate::day04::Day04),
+    Day::new(5, "Hydrothermal Venture", &crate::day05::Day05),
+    Day::new(6, "Lanternfish", &crate::day06::Day06),
+    Day::new(7, "The Treachery of Whales", &crate::day07::Day07),
+    Day::new(8, "Seven Segment Search", &crate::day08::Day08),
+    Day::new(9, "Smoke Basin", &crate::day09::Day09),
+    Day::new(10, "Syntax Scoring", &crate::day10::Day10),
+    Day::new(11, "Dumbo Octopus", &crate::day11::Day11),
+];
+
+/// Every registered day, in order.
+pub fn all() -> &'static [Day] {
+    DAYS
+}
+
+pub fn get(day: u8) -> Result<&'static Day> {
+    DAYS.iter()
+        .find(|d| d.day == day)
+        .ok_or(anyhow::anyhow!("Day {} is not solved yet", day))
+}
+
+/// Fails if any of `overrides` isn't a parameter of at least one of `days`.
+pub fn check_params<'a>(days: impl IntoIterator<Item = &'a Day>, overrides: &Params) -> Result<()> {
+    let known: Vec<&str> = days
+        .into_iter()
+        .flat_map(|day| day.solver.params())
+        .map(|param| param.name)
+        .collect();
+    match overrides.names().find(|name| !known.contains(name)) {
+        Some(name) => Err(anyhow::anyhow!(
+            "Unknown parameter `{}`, expected one of: {}",
+            name,
+            known.join(", ")
+        )),
+        None => Ok(()),
+    }
+}
+
+pub fn print_params() {
+    for day in DAYS {
+        for param in day.solver.params() {
+            let [part1, part2] = param.defaults;
+            let defaults = if part1 == part2 {
+                part1.to_string()
+            } else {
+                format!("{} (part 1), {} (part 2)", part1, part2)
+            };
+            println!(
+                "day {:02}  {}={}  {}",
+                day.day, param.name, defaults, param.description
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod solution_tests {
+    use super::{Param, Params};
+
+    static PARAMS: &[Param] = &[
+        Param::per_part("days", "80", "256", ""),
+        Param::new("size", "5", ""),
+    ];
+
+    #[test]
+    fn test_resolve() {
+        let defaults = Params::default();
+        assert_eq!(
+            Params::resolve(PARAMS, &defaults, 1)
+                .get::<u32>("days")
+                .unwrap(),
+            80
+        );
+        assert_eq!(
+            Params::resolve(PARAMS, &defaults, 2)
+                .get::<u32>("days")
+                .unwrap(),
+            256
+        );
+
+        let overrides: Params = "days=1000".parse().unwrap();
+        assert_eq!(
+            Params::resolve(PARAMS, &overrides, 2)
+                .get::<u32>("days")
+                .unwrap(),
+            1000
+        );
+        assert_eq!(
+            Params::resolve(PARAMS, &overrides, 2)
+                .get::<u32>("size")
+                .unwrap(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_get_invalid() {
+        let params: Params = "days=many".parse().unwrap();
+        assert!(params.get::<u32>("days").is_err());
+        assert!(params.get::<u32>("size").is_err());
+        assert!("days".parse::<Params>().is_err());
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, Verdict};
+
+/// One answer sent to the site and what it said.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+}
+
+/// Every answer submitted so far, oldest first.
+#[derive(Default, Serialize, Deserialize)]
+pub struct History(Vec<Attempt>);
+
+impl History {
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("submissions.json")
+    }
+
+    /// Loads the history at `path`, which is empty if the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<History> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Could not read history from `{}`", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Invalid history in `{}`", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("Could not save history to `{}`", path.display()))
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.0.push(attempt);
+    }
+
+    /// Fails if submitting `answer` at `now` is known to be pointless: the part
+    /// is solved, the answer was already wrong or is past a known bound, or the
+    /// site asked to wait longer.
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: u64) -> Result<()> {
+        if let Some(Attempt {
+            verdict: Verdict::RateLimited { wait_secs },
+            submitted_at,
+            ..
+        }) = self.0.last()
+        {
+            let until = submitted_at + wait_secs;
+            if now < until {
+                anyhow::bail!("Wait another {}s before submitting", until - now);
+            }
+        }
+
+        let value = answer.parse::<i128>().ok();
+        for attempt in self.0.iter().filter(|a| a.day == day && a.part == part) {
+            if attempt.verdict == Verdict::Correct {
+                anyhow::bail!(
+                    "Day {} part {} was already solved with `{}`",
+                    day,
+                    part,
+                    attempt.answer
+                );
+            }
+            if attempt.verdict.is_wrong() && attempt.answer == answer {
+                anyhow::bail!(
+                    "`{}` was already submitted for day {} part {} and was {}",
+                    answer,
+                    day,
+                    part,
+                    attempt.verdict
+                );
+            }
+            let bound = attempt.answer.parse::<i128>().ok();
+            let past_bound = match (attempt.verdict, value, bound) {
+                (Verdict::TooHigh, Some(value), Some(bound)) => value >= bound,
+                (Verdict::TooLow, Some(value), Some(bound)) => value <= bound,
+                _ => false,
+            };
+            if past_bound {
+                anyhow::bail!(
+                    "`{}` must be {} as `{}` was",
+                    answer,
+                    attempt.verdict,
+                    attempt.answer
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submits `answer` unless the history says it is pointless, recording the
+/// attempt. An attempt whose response couldn't be read is recorded as
+/// [`Verdict::Unknown`] before the error is returned.
+pub fn submit(
+    client: &mut Client,
+    history: &mut History,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict> {
+    let now = || {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs())
+    };
+    history.check(day, part, answer, now())?;
+    let verdict = client.submit_answer(day, part, answer);
+    history.record(Attempt {
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict: *verdict.as_ref().unwrap_or(&Verdict::Unknown),
+        submitted_at: now(),
+    });
+    verdict
+}
+
+#[cfg(test)]
+mod submit_tests {
+    use std::net::TcpListener;
+
+    use super::{submit, Attempt, History};
+    use crate::client::{Client, Verdict};
+
+    fn attempt(part: u8, answer: &str, verdict: Verdict, submitted_at: u64) -> Attempt {
+        Attempt {
+            day: 6,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at,
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let mut history = History::default();
+        history.record(attempt(1, "500", Verdict::TooHigh, 100));
+        history.record(attempt(1, "100", Verdict::TooLow, 200));
+        history.record(attempt(1, "abc", Verdict::Wrong, 300));
+        history.record(attempt(2, "42", Verdict::Correct, 400));
+
+        assert!(history.check(6, 1, "300", 1000).is_ok());
+        assert!(history.check(6, 1, "500", 1000).is_err());
+        assert!(history.check(6, 1, "600", 1000).is_err());
+        assert!(history.check(6, 1, "100", 1000).is_err());
+        assert!(history.check(6, 1, "abc", 1000).is_err());
+        assert!(history.check(6, 2, "43", 1000).is_err());
+        assert!(history.check(7, 1, "500", 1000).is_ok());
+
+        history.record(attempt(
+            1,
+            "300",
+            Verdict::RateLimited { wait_secs: 60 },
+            1000,
+        ));
+        assert!(history.check(7, 1, "1", 1030).is_err());
+        assert!(history.check(7, 1, "1", 1060).is_ok());
+    }
+
+    #[test]
+    fn test_submit_records_failures() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let mut client = Client::new(&url, "secret");
+        let mut history = History::default();
+        assert!(submit(&mut client, &mut history, 6, 1, "5934").is_err());
+        assert_eq!(
+            history.0,
+            vec![attempt(
+                1,
+                "5934",
+                Verdict::Unknown,
+                history.0[0].submitted_at
+            )]
+        );
+    }
+}
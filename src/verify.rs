@@ -0,0 +1,201 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use toml::Value;
+
+use crate::{
+    input::Inputs,
+    runner::{self, DayFailure, DayRun},
+    solution::Params,
+};
+
+/// Accepted answers keyed by `dayNN`, as stored in `answers.toml`.
+#[derive(Deserialize, Default)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+#[derive(Deserialize, Default)]
+struct DayAnswers {
+    part1: Option<Value>,
+    part2: Option<Value>,
+}
+
+impl Answers {
+    /// `answers.toml` at the root of this crate.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<Answers> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Could not read answers from `{}`", path.display()))?;
+        Answers::parse(&content).with_context(|| format!("Invalid answers in `{}`", path.display()))
+    }
+
+    pub fn parse(content: &str) -> Result<Answers> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// The recorded answer for `day` and `part`, if there is one.
+    pub fn get(&self, day: u8, part: u8) -> Option<String> {
+        let answers = self.0.get(&format!("day{:02}", day))?;
+        let value = match part {
+            1 => answers.part1.as_ref(),
+            2 => answers.part2.as_ref(),
+            _ => None,
+        }?;
+        Some(value_to_string(value))
+    }
+}
+
+/// Renders a recorded value the way an [`Answer`](crate::solution::Answer) displays.
+pub(crate) fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// The outcome of checking one part against its recorded answer.
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub expected: Option<String>,
+    /// The answer, or why the day could not be solved.
+    pub actual: Result<String, String>,
+}
+
+impl Check {
+    pub fn new(day: u8, part: u8, expected: Option<String>, actual: String) -> Check {
+        Check {
+            day,
+            part,
+            expected,
+            actual: Ok(actual),
+        }
+    }
+
+    /// A part left without an answer because its day failed with `error`.
+    pub fn failed(day: u8, part: u8, expected: Option<String>, error: String) -> Check {
+        Check {
+            day,
+            part,
+            expected,
+            actual: Err(error),
+        }
+    }
+
+    pub fn is_mismatch(&self) -> bool {
+        match (&self.expected, &self.actual) {
+            (Some(expected), Ok(actual)) => expected != actual,
+            _ => false,
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        self.actual.is_err()
+    }
+
+    /// Whether the answer matches a recorded one, i.e. earned a star.
+    pub fn is_correct(&self) -> bool {
+        match (&self.expected, &self.actual) {
+            (Some(expected), Ok(actual)) => expected == actual,
+            _ => false,
+        }
+    }
+}
+
+/// Solves every registered day on `jobs` threads, giving each up to `timeout`,
+/// and checks both parts against `answers`.
+pub fn verify(
+    answers: &Answers,
+    inputs: &Inputs,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<Check> {
+    // the recorded answers are for the default parameters
+    let runs = runner::run_all(inputs, &Params::default(), jobs, timeout);
+    check_runs(answers, &runs)
+}
+
+/// Checks both parts of every run against `answers`, the parts of a failed
+/// run failing too.
+pub fn check_runs(answers: &Answers, runs: &[Result<DayRun, DayFailure>]) -> Vec<Check> {
+    let mut checks = Vec::new();
+    for run in runs {
+        for part in [1, 2] {
+            checks.push(match run {
+                Ok(run) => {
+                    let (actual, _) = run.part(part).expect("Runs have both parts");
+                    Check::new(
+                        run.day,
+                        part,
+                        answers.get(run.day, part),
+                        actual.to_string(),
+                    )
+                }
+                Err(failure) => Check::failed(
+                    failure.day,
+                    part,
+                    answers.get(failure.day, part),
+                    format!("{:#}", failure.error),
+                ),
+            });
+        }
+    }
+    checks
+}
+
+pub fn print_checks(checks: &[Check]) {
+    for check in checks {
+        let status = match (&check.expected, &check.actual) {
+            (_, Err(error)) => format!("FAILED {}", error),
+            (None, Ok(actual)) => format!("{} (no recorded answer)", actual),
+            (Some(_), Ok(actual)) if !check.is_mismatch() => format!("{} ok", actual),
+            (Some(expected), Ok(actual)) => {
+                format!("MISMATCH expected {}, got {}", expected, actual)
+            }
+        };
+        println!("day {:02} part {}: {}", check.day, check.part, status);
+    }
+}
+
+#[cfg(test)]
+mod verify_tests {
+    use super::{Answers, Check};
+
+    static ANSWERS: &str = r#"
+[day01]
+part1 = 7
+part2 = "5"
+
+[day06]
+part2 = 26984457539
+"#;
+
+    #[test]
+    fn test_get() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.get(1, 1).unwrap(), "7");
+        assert_eq!(answers.get(1, 2).unwrap(), "5");
+        assert_eq!(answers.get(6, 1), None);
+        assert_eq!(answers.get(6, 2).unwrap(), "26984457539");
+        assert_eq!(answers.get(2, 1), None);
+    }
+
+    #[test]
+    fn test_is_mismatch() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert!(!Check::new(1, 1, answers.get(1, 1), "7".to_string()).is_mismatch());
+        assert!(Check::new(1, 2, answers.get(1, 2), "6".to_string()).is_mismatch());
+        assert!(!Check::new(2, 1, answers.get(2, 1), "1".to_string()).is_mismatch());
+
+        let failed = Check::failed(1, 1, answers.get(1, 1), "timed out".to_string());
+        assert!(failed.is_failure() && !failed.is_mismatch() && !failed.is_correct());
+    }
+}
@@ -0,0 +1,169 @@
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
+
+use anyhow::Result;
+
+use crate::{
+    cancel,
+    solution::{Day, Params},
+};
+
+/// A file being watched along with what it held and produced the last time.
+struct Watched {
+    path: PathBuf,
+    /// The content or why it couldn't be read, `None` before the first poll.
+    content: Option<Result<String, String>>,
+    answers: Option<[String; 2]>,
+}
+
+impl Watched {
+    fn new(path: PathBuf) -> Watched {
+        Watched {
+            path,
+            content: None,
+            answers: None,
+        }
+    }
+
+    /// Re-reads the file, returning its content, or why it could not be read,
+    /// if that changed since the last poll.
+    fn poll(&mut self) -> Option<Result<String, String>> {
+        let content = fs::read_to_string(&self.path).map_err(|err| err.to_string());
+        if self.content.as_ref() == Some(&content) {
+            return None;
+        }
+        self.content = Some(content.clone());
+        Some(content)
+    }
+}
+
+/// Polls `files` every `interval` and re-solves both parts of `day` against
+/// any of them whose contents changed, giving up on a solve that takes longer
+/// than `timeout`. Never returns.
+pub fn watch(
+    day: &'static Day,
+    files: Vec<PathBuf>,
+    params: &Params,
+    interval: Duration,
+    timeout: Duration,
+) -> ! {
+    let mut watched: Vec<Watched> = files.into_iter().map(Watched::new).collect();
+    loop {
+        for file in watched.iter_mut() {
+            match file.poll() {
+                None => {}
+                Some(Err(err)) => {
+                    println!("[{}] could not be read - {}", file.path.display(), err)
+                }
+                Some(Ok(input)) => {
+                    let answers = solve(day, input, params, timeout);
+                    for (i, answer) in answers.iter().enumerate() {
+                        let previous = file.answers.as_ref().map(|a| a[i].as_str());
+                        println!(
+                            "[{}] part {}: {}",
+                            file.path.display(),
+                            i + 1,
+                            describe(previous, answer)
+                        );
+                    }
+                    file.answers = Some(answers);
+                }
+            }
+        }
+        thread::sleep(interval);
+    }
+}
+
+/// Solves both parts, turning failures, panics and running out of `timeout`
+/// into messages since a half edited input shouldn't stop the watch.
+fn solve(day: &'static Day, input: String, params: &Params, timeout: Duration) -> [String; 2] {
+    let params = params.clone();
+    let solved = cancel::with_timeout(Some(timeout), move || {
+        let parsed = match catch_panic(|| day.solver.parse(&input, &params)) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                let message = format!("parse error: {:#}", err);
+                return Ok([message.clone(), message]);
+            }
+        };
+        Ok([1, 2].map(
+            |part| match catch_panic(|| day.solver.part(&parsed, part, &params)) {
+                Ok(answer) => answer.to_string(),
+                Err(err) => format!("error: {:#}", err),
+            },
+        ))
+    });
+    solved.unwrap_or_else(|err| {
+        let message = format!("error: {:#}", err);
+        [message.clone(), message]
+    })
+}
+
+fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    // the solvers hold no state a panic could leave broken
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|_| Err(anyhow::anyhow!("The solver panicked")))
+}
+
+fn describe(previous: Option<&str>, current: &str) -> String {
+    match previous {
+        None => current.to_string(),
+        Some(previous) if previous == current => format!("{} (unchanged)", current),
+        Some(previous) => format!("{} (was {})", current, previous),
+    }
+}
+
+#[cfg(test)]
+mod watch_tests {
+    use std::{env, fs, time::Duration};
+
+    use super::{describe, solve, Watched};
+    use crate::solution::{self, Params};
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(describe(None, "4512"), "4512");
+        assert_eq!(describe(Some("4512"), "4512"), "4512 (unchanged)");
+        assert_eq!(describe(Some("4512"), "1924"), "1924 (was 4512)");
+    }
+
+    #[test]
+    fn test_poll() {
+        let path = env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        let mut file = Watched::new(path.clone());
+        assert!(file.poll().unwrap().is_err());
+        assert_eq!(file.poll(), None);
+
+        fs::write(&path, "3,4").unwrap();
+        assert_eq!(file.poll(), Some(Ok("3,4".to_string())));
+        assert_eq!(file.poll(), None);
+
+        fs::remove_file(&path).unwrap();
+        assert!(file.poll().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_solve() {
+        let timeout = Duration::from_secs(10);
+        let params = Params::default();
+        let day = solution::get(6).unwrap();
+        assert_eq!(
+            solve(day, "3,4,3,1,2".to_string(), &params, timeout),
+            ["5934", "26984457539"]
+        );
+        assert!(solve(day, "3,x".to_string(), &params, timeout)[0].starts_with("parse error"));
+
+        let day = solution::get(11).unwrap();
+        let params = "steps=4000000000".parse().unwrap();
+        let input = "5483143223\n2745854711".to_string();
+        assert_eq!(
+            solve(day, input, &params, Duration::from_millis(50))[0],
+            "error: timed out after 0.05s"
+        );
+    }
+}
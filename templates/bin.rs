@@ -0,0 +1,7 @@
+use advent_of_code_2021::{input::Inputs, runner, solution::Params};
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    let input = Inputs::from_env().load({{N}})?;
+    runner::run_day({{N}}, None, &input, &Params::default())
+}
@@ -0,0 +1,60 @@
+//! Day {{N}}: {{TITLE}}.
+
+use anyhow::Result;
+
+use crate::solution::{Params, Solution};
+
+/// The [`Solution`] for day {{N}}.
+pub struct Day{{NN}};
+
+impl Solution for Day{{NN}} {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str, _params: &Params) -> Result<Vec<String>> {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Vec<String>, _params: &Params) -> Result<u32> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Vec<String>, _params: &Params) -> Result<u32> {
+        part2(input)
+    }
+}
+
+/// The puzzle input, one line at a time.
+pub fn parse_input(input: &str) -> Result<Vec<String>> {
+    Ok(input.lines().map(String::from).collect())
+}
+
+/// The answer to part 1, 0 until it is solved.
+pub fn part1(_lines: &[String]) -> Result<u32> {
+    Ok(0)
+}
+
+/// The answer to part 2, 0 until it is solved.
+pub fn part2(_lines: &[String]) -> Result<u32> {
+    Ok(0)
+}
+
+#[cfg(test)]
+mod day{{NN}}_tests {
+    use super::{parse_input, part1, part2};
+
+    static TEST_INPUT: &str = r"";
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_part1() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()).unwrap(), 0);
+    }
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_part2() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()).unwrap(), 0);
+    }
+}